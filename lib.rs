@@ -2,9 +2,9 @@ pub mod errors;
 pub mod state;
 pub mod instructions;
 pub mod accounts;
+pub mod rating;
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
 
 declare_id!("WZNBurnPlay111111111111111111111111111111111");
 
@@ -17,16 +17,89 @@ pub mod wzn_burn_play {
     use super::*;
 
     // Game Management
-    pub fn initialize_game(ctx: Context<InitializeGame>, monthly_pass_cost: u64) -> Result<()> {
-        instructions::initialize_game(ctx, monthly_pass_cost)
+    pub fn initialize_game(ctx: Context<InitializeGame>, monthly_pass_cost: u64, max_prepaid_months: u8) -> Result<()> {
+        instructions::initialize_game(ctx, monthly_pass_cost, max_prepaid_months)
     }
 
-    pub fn burn_to_play(ctx: Context<BurnToPlay>, amount: u64) -> Result<()> {
-        instructions::burn_to_play(ctx, amount)
+    // Fee Management
+    pub fn initialize_fee_config(ctx: Context<InitializeFeeConfig>, fee_mode: FeeMode, approved_sponsors: Vec<Pubkey>) -> Result<()> {
+        instructions::initialize_fee_config(ctx, fee_mode, approved_sponsors)
     }
 
-    pub fn check_game_access(ctx: Context<CheckGameAccess>) -> Result<()> {
-        instructions::check_game_access(ctx)
+    // Pricing
+    pub fn initialize_price_feed(ctx: Context<InitializePriceFeed>, updater: Pubkey) -> Result<()> {
+        instructions::initialize_price_feed(ctx, updater)
+    }
+
+    pub fn update_price_feed(ctx: Context<UpdatePriceFeed>, price: u64, confidence: u64, expo: i32) -> Result<()> {
+        instructions::update_price_feed(ctx, price, confidence, expo)
+    }
+
+    pub fn configure_pricing(
+        ctx: Context<ConfigurePricing>,
+        pricing_mode: PricingMode,
+        reference_pass_cost: u64,
+        max_price_age: i64,
+        max_price_confidence_bps: u16,
+        min_pass_cost: u64,
+        max_pass_cost: u64,
+    ) -> Result<()> {
+        instructions::configure_pricing(ctx, pricing_mode, reference_pass_cost, max_price_age, max_price_confidence_bps, min_pass_cost, max_pass_cost)
+    }
+
+    // Pass Tiers
+    pub fn configure_pass_tier(ctx: Context<ConfigurePassTier>, tier_id: u8, config: PassTierConfig) -> Result<()> {
+        instructions::configure_pass_tier(ctx, tier_id, config)
+    }
+
+    pub fn burn_to_play(ctx: Context<BurnToPlay>, tier_id: u8, periods: u8) -> Result<()> {
+        instructions::burn_to_play(ctx, tier_id, periods)
+    }
+
+    pub fn burn_to_play_for(ctx: Context<BurnToPlayFor>, tier_id: u8, periods: u8, beneficiary: Pubkey) -> Result<()> {
+        instructions::burn_to_play_for(ctx, tier_id, periods, beneficiary)
+    }
+
+    pub fn check_game_access(ctx: Context<CheckGameAccess>, mode: GameMode) -> Result<()> {
+        instructions::check_game_access(ctx, mode)
+    }
+
+    pub fn close_expired_ticket(ctx: Context<CloseExpiredTicket>) -> Result<()> {
+        instructions::close_expired_ticket(ctx)
+    }
+
+    // Moderation
+    pub fn initialize_role_registry(ctx: Context<InitializeRoleRegistry>, moderators: Vec<Pubkey>) -> Result<()> {
+        instructions::initialize_role_registry(ctx, moderators)
+    }
+
+    pub fn update_moderators(ctx: Context<UpdateRoleRegistry>, moderators: Vec<Pubkey>) -> Result<()> {
+        instructions::update_moderators(ctx, moderators)
+    }
+
+    pub fn update_game_servers(ctx: Context<UpdateRoleRegistry>, game_servers: Vec<Pubkey>) -> Result<()> {
+        instructions::update_game_servers(ctx, game_servers)
+    }
+
+    pub fn update_arbiters(ctx: Context<UpdateRoleRegistry>, arbiters: Vec<Pubkey>) -> Result<()> {
+        instructions::update_arbiters(ctx, arbiters)
+    }
+
+    pub fn suspend_pass(ctx: Context<ModeratePass>, player: Pubkey, reason_code: u16, suspension_end: i64) -> Result<()> {
+        instructions::suspend_pass(ctx, player, reason_code, suspension_end)
+    }
+
+    pub fn reinstate_pass(ctx: Context<ModeratePass>, player: Pubkey) -> Result<()> {
+        instructions::reinstate_pass(ctx, player)
+    }
+
+    // Pass Token
+    pub fn create_pass_token(ctx: Context<CreatePassToken>) -> Result<()> {
+        instructions::create_pass_token(ctx)
+    }
+
+    pub fn sync_pass_token(ctx: Context<SyncPassToken>, player: Pubkey) -> Result<()> {
+        instructions::sync_pass_token(ctx, player)
     }
 
     // Vault Management
@@ -38,6 +111,10 @@ pub mod wzn_burn_play {
         instructions::initialize_prize_vault(ctx)
     }
 
+    pub fn initialize_recovery_vault(ctx: Context<InitializeRecoveryVault>) -> Result<()> {
+        instructions::initialize_recovery_vault(ctx)
+    }
+
     pub fn deposit_to_prize_vault(ctx: Context<DepositToPrizeVault>, amount: u64) -> Result<()> {
         instructions::deposit_to_prize_vault(ctx, amount)
     }
@@ -64,19 +141,92 @@ pub mod wzn_burn_play {
         instructions::initialize_emergency_recovery(ctx, members)
     }
 
+    pub fn approve_emergency_unlock(ctx: Context<ApproveEmergencyUnlock>, amount: u64, recipient: Pubkey) -> Result<()> {
+        instructions::approve_emergency_unlock(ctx, amount, recipient)
+    }
+
     pub fn emergency_unlock(ctx: Context<EmergencyUnlock>, amount: u64, percentage: u64) -> Result<()> {
         instructions::emergency_unlock(ctx, amount, percentage)
     }
 
     // Player Score Management
-    pub fn update_player_score(ctx: Context<UpdatePlayerScore>, games_played: u32, games_won: u32, rating_change: i32) -> Result<()> {
-        instructions::update_player_score(ctx, games_played, games_won, rating_change)
+    pub fn report_match_result(
+        ctx: Context<ReportMatchResult>,
+        game_id: u64,
+        players: [Pubkey; MATCH_PLAYERS],
+        result: MatchResult,
+    ) -> Result<()> {
+        instructions::report_match_result(ctx, game_id, players, result)
+    }
+
+    pub fn propose_match_result(
+        ctx: Context<ProposeMatchResult>,
+        game_id: u64,
+        players: [Pubkey; MATCH_PLAYERS],
+        result: MatchResult,
+    ) -> Result<()> {
+        instructions::propose_match_result(ctx, game_id, players, result)
+    }
+
+    pub fn attest_match_result(ctx: Context<AttestMatchResult>, game_id: u64) -> Result<()> {
+        instructions::attest_match_result(ctx, game_id)
+    }
+
+    pub fn reject_match_result(ctx: Context<RejectMatchResult>, game_id: u64) -> Result<()> {
+        instructions::reject_match_result(ctx, game_id)
+    }
+
+    pub fn close_stale_match_proposal(ctx: Context<CloseStaleMatchProposal>, game_id: u64) -> Result<()> {
+        instructions::close_stale_match_proposal(ctx, game_id)
     }
 
     pub fn distribute_prize(ctx: Context<DistributePrize>, amount: u64) -> Result<()> {
         instructions::distribute_prize(ctx, amount)
     }
 
+    pub fn migrate_player_score(ctx: Context<MigratePlayerScore>, player: Pubkey) -> Result<()> {
+        instructions::migrate_player_score(ctx, player)
+    }
+
+    // Leaderboard
+    pub fn open_leaderboard(ctx: Context<OpenLeaderboard>) -> Result<()> {
+        instructions::open_leaderboard(ctx)
+    }
+
+    pub fn sync_monthly_rank(ctx: Context<SyncMonthlyRank>, player: Pubkey) -> Result<()> {
+        instructions::sync_monthly_rank(ctx, player)
+    }
+
+    // Match Disputes
+    pub fn dispute_match(ctx: Context<DisputeMatch>, game_id: u64, evidence_hash: [u8; 32]) -> Result<()> {
+        instructions::dispute_match(ctx, game_id, evidence_hash)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, game_id: u64, void: bool) -> Result<()> {
+        instructions::resolve_dispute(ctx, game_id, void)
+    }
+
+    pub fn finalize_match(ctx: Context<FinalizeMatch>, game_id: u64) -> Result<()> {
+        instructions::finalize_match(ctx, game_id)
+    }
+
+    // Account Cleanup
+    pub fn close_player_pass(ctx: Context<ClosePlayerPass>) -> Result<()> {
+        instructions::close_player_pass(ctx)
+    }
+
+    pub fn close_inactive_player_pass(ctx: Context<CloseInactivePlayerPass>, player: Pubkey) -> Result<()> {
+        instructions::close_inactive_player_pass(ctx, player)
+    }
+
+    pub fn close_player_score(ctx: Context<ClosePlayerScore>) -> Result<()> {
+        instructions::close_player_score(ctx)
+    }
+
+    pub fn close_inactive_player_score(ctx: Context<CloseInactivePlayerScore>, player: Pubkey) -> Result<()> {
+        instructions::close_inactive_player_score(ctx, player)
+    }
+
     // Monthly Reset
     pub fn monthly_reset(ctx: Context<MonthlyReset>) -> Result<()> {
         instructions::monthly_reset(ctx)
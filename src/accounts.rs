@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Burn, Mint};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::state::*;
 use crate::errors::GameError;
@@ -10,19 +12,126 @@ pub struct InitializeGame<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 1 + 32 + 32 + 8 + 1 + 8 + 8 + 8 + 1,
+        space = GAME_STATE_SPACE,
         seeds = [GAME_STATE_SEED],
         bump
     )]
     pub game_state: Account<'info, GameState>,
     
     pub authority: Signer<'info>,
-    pub wzn_mint: Account<'info, Mint>,
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+// Fee Management
 #[derive(Accounts)]
+pub struct InitializeFeeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 1 + 4 + 32 * MAX_FEE_SPONSORS + 1,
+        seeds = [FEE_CONFIG_SEED],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized,
+        constraint = game_state.authority == authority.key() @ GameError::NotAuthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Pricing
+#[derive(Accounts)]
+pub struct InitializePriceFeed<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 32 + 8 + 8 + 4 + 8 + 1,
+        seeds = [PRICE_FEED_SEED],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized,
+        constraint = game_state.authority == authority.key() @ GameError::NotAuthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(
+        mut,
+        seeds = [PRICE_FEED_SEED],
+        bump = price_feed.bump,
+        constraint = price_feed.is_initialized @ GameError::PriceFeedNotInitialized,
+        constraint = price_feed.updater == updater.key() @ GameError::NotAuthorized
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    
+    pub updater: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePricing<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized,
+        constraint = game_state.authority == authority.key() @ GameError::NotAuthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(constraint = price_feed.is_initialized @ GameError::PriceFeedNotInitialized)]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u8)]
+pub struct ConfigurePassTier<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized,
+        constraint = game_state.authority == authority.key() @ GameError::NotAuthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 1 + 1 + 4 + 8 + 1 + 1 + 1 + 1,
+        seeds = [PASS_TIER_SEED, &[tier_id]],
+        bump
+    )]
+    pub pass_tier: Account<'info, PassTier>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u8)]
 pub struct BurnToPlay<'info> {
     #[account(
         mut,
@@ -41,39 +150,1214 @@ pub struct BurnToPlay<'info> {
     pub burn_vault: Account<'info, BurnVault>,
     
     #[account(
-        mut,
+        seeds = [PASS_TIER_SEED, &[tier_id]],
+        bump = pass_tier.bump,
+        constraint = pass_tier.is_active @ GameError::PassTierInactive
+    )]
+    pub pass_tier: Account<'info, PassTier>,
+    
+    #[account(
+        seeds = [FEE_CONFIG_SEED],
+        bump = fee_config.bump,
+        constraint = fee_config.is_initialized @ GameError::FeeConfigNotInitialized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    
+    #[account(
         init_if_needed,
-        payer = player,
-        space = 8 + 1 + 32 + 8 + 8 + 1 + 4 + 8,
+        payer = fee_payer,
+        space = PLAYER_PASS_SPACE,
         seeds = [PLAYER_PASS_SEED, player.key().as_ref()],
         bump
     )]
     pub player_pass: Account<'info, PlayerPass>,
     
+    #[account(
+        address = game_state.price_feed @ GameError::InvalidPriceFeed,
+        constraint = price_feed.is_initialized @ GameError::PriceFeedNotInitialized
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
     #[account(
         mut,
         constraint = player_token_account.owner == player.key() @ GameError::InvalidTokenAccount,
         constraint = player_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
     )]
-    pub player_token_account: Account<'info, TokenAccount>,
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [BURN_VAULT_TOKEN_SEED],
+        bump = burn_vault.token_account_bump,
+        constraint = burn_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint,
+        constraint = burn_vault_token_account.owner == burn_vault.key() @ GameError::InvalidTokenAccount
+    )]
+    pub burn_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    // The recovery and prize vaults are only needed when the routing table sends a leg there
+    #[account(
+        mut,
+        seeds = [RECOVERY_VAULT_SEED],
+        bump = recovery_vault.bump,
+        constraint = recovery_vault.is_initialized @ GameError::RecoveryVaultNotInitialized
+    )]
+    pub recovery_vault: Option<Account<'info, RecoveryVault>>,
+    
+    #[account(
+        mut,
+        seeds = [RECOVERY_VAULT_TOKEN_SEED],
+        bump,
+        constraint = recovery_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub recovery_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED],
+        bump = prize_vault.bump,
+        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+    )]
+    pub prize_vault: Option<Account<'info, PrizeVault>>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_TOKEN_SEED],
+        bump,
+        constraint = prize_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub prize_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_valid_fee_payer(&fee_config, &fee_payer.key(), &player.key()) @ GameError::FeePayerNotApproved
+    )]
+    pub fee_payer: Signer<'info>,
+    #[account(mut, address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub pass_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Pass token authority PDA, verified when the pass token is synced
+    pub pass_authority: Option<UncheckedAccount<'info>>,
+    pub pass_token_program: Option<Program<'info, Token2022>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u8, periods: u8, beneficiary: Pubkey)]
+pub struct BurnToPlayFor<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [BURN_VAULT_SEED],
+        bump = burn_vault.bump,
+        constraint = burn_vault.is_initialized @ GameError::BurnVaultNotInitialized
+    )]
+    pub burn_vault: Account<'info, BurnVault>,
+    
+    #[account(
+        seeds = [PASS_TIER_SEED, &[tier_id]],
+        bump = pass_tier.bump,
+        constraint = pass_tier.is_active @ GameError::PassTierInactive
+    )]
+    pub pass_tier: Account<'info, PassTier>,
+    
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = PLAYER_PASS_SPACE,
+        seeds = [PLAYER_PASS_SEED, beneficiary.as_ref()],
+        bump
+    )]
+    pub player_pass: Account<'info, PlayerPass>,
+    
+    #[account(
+        address = game_state.price_feed @ GameError::InvalidPriceFeed,
+        constraint = price_feed.is_initialized @ GameError::PriceFeedNotInitialized
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    #[account(
+        mut,
+        constraint = sponsor_token_account.owner == sponsor.key() @ GameError::InvalidTokenAccount,
+        constraint = sponsor_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub sponsor_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [BURN_VAULT_TOKEN_SEED],
+        bump = burn_vault.token_account_bump,
+        constraint = burn_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint,
+        constraint = burn_vault_token_account.owner == burn_vault.key() @ GameError::InvalidTokenAccount
+    )]
+    pub burn_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    // The recovery and prize vaults are only needed when the routing table sends a leg there
+    #[account(
+        mut,
+        seeds = [RECOVERY_VAULT_SEED],
+        bump = recovery_vault.bump,
+        constraint = recovery_vault.is_initialized @ GameError::RecoveryVaultNotInitialized
+    )]
+    pub recovery_vault: Option<Account<'info, RecoveryVault>>,
+    
+    #[account(
+        mut,
+        seeds = [RECOVERY_VAULT_TOKEN_SEED],
+        bump,
+        constraint = recovery_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub recovery_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED],
+        bump = prize_vault.bump,
+        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+    )]
+    pub prize_vault: Option<Account<'info, PrizeVault>>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_TOKEN_SEED],
+        bump,
+        constraint = prize_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub prize_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    #[account(mut, address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub pass_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Pass token authority PDA, verified when the pass token is synced
+    pub pass_authority: Option<UncheckedAccount<'info>>,
+    pub pass_token_program: Option<Program<'info, Token2022>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CheckGameAccess<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        seeds = [FEE_CONFIG_SEED],
+        bump = fee_config.bump,
+        constraint = fee_config.is_initialized @ GameError::FeeConfigNotInitialized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        space = PLAYER_PASS_SPACE,
+        seeds = [PLAYER_PASS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_pass: Account<'info, PlayerPass>,
+    
+    // Only needed when playing on a pass; free games have no tier
+    #[account(
+        seeds = [PASS_TIER_SEED, &[player_pass.tier_id]],
+        bump = pass_tier.bump
+    )]
+    pub pass_tier: Option<Account<'info, PassTier>>,
+    
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + 1 + 32 + 1 + 8 + 8 + 8 + 1 + 32,
+        seeds = [MATCH_TICKET_SEED, player.key().as_ref(), &player_pass.ticket_nonce.to_le_bytes()],
+        bump
+    )]
+    pub match_ticket: Account<'info, MatchTicket>,
+    
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_valid_fee_payer(&fee_config, &fee_payer.key(), &player.key()) @ GameError::FeePayerNotApproved
+    )]
+    pub fee_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredTicket<'info> {
+    #[account(
+        mut,
+        close = ticket_payer,
+        seeds = [MATCH_TICKET_SEED, match_ticket.player.as_ref(), &match_ticket.nonce.to_le_bytes()],
+        bump = match_ticket.bump
+    )]
+    pub match_ticket: Account<'info, MatchTicket>,
+    
+    #[account(mut, address = match_ticket.payer @ GameError::NotAuthorized)]
+    pub ticket_payer: SystemAccount<'info>,
+}
+
+// Moderation
+#[derive(Accounts)]
+pub struct InitializeRoleRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 4 + 32 * MAX_MODERATORS + 1 + 4 + 32 * MAX_GAME_SERVERS + 4 + 32 * MAX_ARBITERS,
+        seeds = [ROLE_REGISTRY_SEED],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized,
+        constraint = game_state.authority == authority.key() @ GameError::NotAuthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRoleRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [ROLE_REGISTRY_SEED],
+        bump = role_registry.bump,
+        constraint = role_registry.is_initialized @ GameError::RoleRegistryNotInitialized
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized,
+        constraint = game_state.authority == authority.key() @ GameError::NotAuthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct ModeratePass<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED],
+        bump = role_registry.bump,
+        constraint = role_registry.is_initialized @ GameError::RoleRegistryNotInitialized
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_PASS_SEED, player.as_ref()],
+        bump = player_pass.bump,
+        constraint = player_pass.player == player @ GameError::NotAuthorized
+    )]
+    pub player_pass: Account<'info, PlayerPass>,
+    
+    #[account(
+        constraint = is_moderator(&role_registry, &game_state, &moderator.key()) @ GameError::NotAuthorized
+    )]
+    pub moderator: Signer<'info>,
+    
+    #[account(mut)]
+    pub pass_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Pass token authority PDA, verified when the pass token is synced
+    pub pass_authority: Option<UncheckedAccount<'info>>,
+    pub pass_token_program: Option<Program<'info, Token2022>>,
+}
+
+// Pass Token
+#[derive(Accounts)]
+pub struct CreatePassToken<'info> {
+    #[account(
+        seeds = [FEE_CONFIG_SEED],
+        bump = fee_config.bump,
+        constraint = fee_config.is_initialized @ GameError::FeeConfigNotInitialized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_PASS_SEED, player.key().as_ref()],
+        bump = player_pass.bump,
+        constraint = player_pass.pass_token_mint.is_none() @ GameError::PassTokenExists
+    )]
+    pub player_pass: Account<'info, PlayerPass>,
+    
+    /// CHECK: Created and initialized as a Token-2022 mint in the handler
+    #[account(
+        mut,
+        seeds = [PASS_MINT_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub pass_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA holding mint, freeze, permanent delegate and metadata authority over pass tokens
+    #[account(
+        seeds = [PASS_AUTHORITY_SEED],
+        bump
+    )]
+    pub pass_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: The player's associated token account, created and verified by the associated token program
+    #[account(mut)]
+    pub pass_token_account: UncheckedAccount<'info>,
+    
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_valid_fee_payer(&fee_config, &fee_payer.key(), &player.key()) @ GameError::FeePayerNotApproved
+    )]
+    pub fee_payer: Signer<'info>,
+    pub pass_token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct SyncPassToken<'info> {
+    #[account(
+        seeds = [PLAYER_PASS_SEED, player.as_ref()],
+        bump = player_pass.bump
+    )]
+    pub player_pass: Account<'info, PlayerPass>,
+    
+    #[account(mut)]
+    pub pass_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub pass_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Pass token authority PDA
+    #[account(
+        seeds = [PASS_AUTHORITY_SEED],
+        bump
+    )]
+    pub pass_authority: UncheckedAccount<'info>,
+    
+    pub pass_token_program: Program<'info, Token2022>,
+}
+
+// Vault Management
+#[derive(Accounts)]
+pub struct InitializeBurnVault<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 32 + 1,
+        seeds = [BURN_VAULT_SEED],
+        bump
+    )]
+    pub burn_vault: Account<'info, BurnVault>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [BURN_VAULT_TOKEN_SEED],
+        bump,
+        token::mint = wzn_mint,
+        token::authority = burn_vault,
+        token::token_program = token_program
+    )]
+    pub burn_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePrizeVault<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 8 + 8 + 8 + 1 + 32 + 1,
+        seeds = [PRIZE_VAULT_SEED],
+        bump
+    )]
+    pub prize_vault: Account<'info, PrizeVault>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [PRIZE_VAULT_TOKEN_SEED],
+        bump,
+        token::mint = wzn_mint,
+        token::authority = prize_vault,
+        token::token_program = token_program
+    )]
+    pub prize_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRecoveryVault<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 8 + 1 + 32 + 1 + 8,
+        seeds = [RECOVERY_VAULT_SEED],
+        bump
+    )]
+    pub recovery_vault: Account<'info, RecoveryVault>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [RECOVERY_VAULT_TOKEN_SEED],
+        bump,
+        token::mint = wzn_mint,
+        token::authority = recovery_vault,
+        token::token_program = token_program
+    )]
+    pub recovery_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized,
+        constraint = game_state.authority == authority.key() @ GameError::NotAuthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct DepositToPrizeVault<'info> {
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED],
+        bump = prize_vault.bump,
+        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+    )]
+    pub prize_vault: Account<'info, PrizeVault>,
+    
+    #[account(
+        mut,
+        constraint = from_token_account.owner == authority.key() @ GameError::InvalidTokenAccount,
+        constraint = from_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub from_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_TOKEN_SEED],
+        bump = prize_vault.token_account_bump,
+        constraint = prize_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint,
+        constraint = prize_vault_token_account.owner == prize_vault.key() @ GameError::InvalidTokenAccount
+    )]
+    pub prize_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    pub authority: Signer<'info>,
+    #[account(address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// DAO Governance
+#[derive(Accounts)]
+pub struct InitializeDAO<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 4 + 32 * 50 + 4 + 8 + 1 + 4 + 100 * 50, // Space for 50 members and 50 proposals
+        seeds = [DAO_GOVERNANCE_SEED],
+        bump
+    )]
+    pub dao_governance: Account<'info, DAOGovernance>,
+    
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED],
+        bump = dao_governance.bump,
+        constraint = dao_governance.is_initialized @ GameError::DAONotInitialized
+    )]
+    pub dao_governance: Account<'info, DAOGovernance>,
+    
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VoteOnProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED],
+        bump = dao_governance.bump,
+        constraint = dao_governance.is_initialized @ GameError::DAONotInitialized
+    )]
+    pub dao_governance: Account<'info, DAOGovernance>,
+    
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED],
+        bump = dao_governance.bump,
+        constraint = dao_governance.is_initialized @ GameError::DAONotInitialized
+    )]
+    pub dao_governance: Account<'info, DAOGovernance>,
+    
+    #[account(
+        mut,
+        seeds = [BURN_VAULT_SEED],
+        bump = burn_vault.bump,
+        constraint = burn_vault.is_initialized @ GameError::BurnVaultNotInitialized
+    )]
+    pub burn_vault: Account<'info, BurnVault>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED],
+        bump = prize_vault.bump,
+        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+    )]
+    pub prize_vault: Account<'info, PrizeVault>,
+    
+    #[account(
+        mut,
+        seeds = [BURN_VAULT_TOKEN_SEED],
+        bump = burn_vault.token_account_bump,
+        constraint = burn_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint,
+        constraint = burn_vault_token_account.owner == burn_vault.key() @ GameError::InvalidTokenAccount
+    )]
+    pub burn_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_TOKEN_SEED],
+        bump = prize_vault.token_account_bump,
+        constraint = prize_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint,
+        constraint = prize_vault_token_account.owner == prize_vault.key() @ GameError::InvalidTokenAccount
+    )]
+    pub prize_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    // Only fee proposals need the fee config
+    #[account(
+        mut,
+        seeds = [FEE_CONFIG_SEED],
+        bump = fee_config.bump,
+        constraint = fee_config.is_initialized @ GameError::FeeConfigNotInitialized
+    )]
+    pub fee_config: Option<Account<'info, FeeConfig>>,
+    
+    // Only recovery withdrawals need the recovery vault and a recipient token account
+    #[account(
+        mut,
+        seeds = [RECOVERY_VAULT_SEED],
+        bump = recovery_vault.bump,
+        constraint = recovery_vault.is_initialized @ GameError::RecoveryVaultNotInitialized
+    )]
+    pub recovery_vault: Option<Account<'info, RecoveryVault>>,
+    
+    #[account(
+        mut,
+        seeds = [RECOVERY_VAULT_TOKEN_SEED],
+        bump,
+        constraint = recovery_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub recovery_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    // Only prize awards need the recipient's score
+    #[account(
+        mut,
+        seeds = [PLAYER_SCORE_SEED, player_score.player.as_ref()],
+        bump = player_score.bump
+    )]
+    pub player_score: Option<Account<'info, PlayerScore>>,
+    
+    // Only suspensions and reinstatements need the player's pass; its token is brought in line by sync_pass_token
+    #[account(
+        mut,
+        seeds = [PLAYER_PASS_SEED, player_pass.player.as_ref()],
+        bump = player_pass.bump
+    )]
+    pub player_pass: Option<Account<'info, PlayerPass>>,
+    
+    pub executor: Signer<'info>,
+    #[account(address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Emergency Recovery
+#[derive(Accounts)]
+pub struct InitializeEmergencyRecovery<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 4 + 32 * 20 + 4 + 8 + 8 + 1 + 1 + 8 + 8 + 32 + 8 + 4 + 32 * 20, // Space for 20 backup members and their approvals
+        seeds = [EMERGENCY_RECOVERY_SEED],
+        bump
+    )]
+    pub emergency_recovery: Account<'info, EmergencyRecovery>,
+    
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveEmergencyUnlock<'info> {
+    #[account(
+        mut,
+        seeds = [EMERGENCY_RECOVERY_SEED],
+        bump = emergency_recovery.bump,
+        constraint = emergency_recovery.is_initialized @ GameError::EmergencyRecoveryNotInitialized
+    )]
+    pub emergency_recovery: Account<'info, EmergencyRecovery>,
+    
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmergencyUnlock<'info> {
+    #[account(
+        mut,
+        seeds = [EMERGENCY_RECOVERY_SEED],
+        bump = emergency_recovery.bump,
+        constraint = emergency_recovery.is_initialized @ GameError::EmergencyRecoveryNotInitialized
+    )]
+    pub emergency_recovery: Account<'info, EmergencyRecovery>,
+    
+    #[account(
+        mut,
+        seeds = [BURN_VAULT_SEED],
+        bump = burn_vault.bump,
+        constraint = burn_vault.is_initialized @ GameError::BurnVaultNotInitialized
+    )]
+    pub burn_vault: Account<'info, BurnVault>,
+    
+    #[account(
+        mut,
+        seeds = [BURN_VAULT_TOKEN_SEED],
+        bump = burn_vault.token_account_bump,
+        constraint = burn_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint,
+        constraint = burn_vault_token_account.owner == burn_vault.key() @ GameError::InvalidTokenAccount
+    )]
+    pub burn_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == recipient.key() @ GameError::InvalidTokenAccount,
+        constraint = recipient_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    pub recipient: Signer<'info>,
+    #[account(address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Player Score Management
+#[derive(Accounts)]
+#[instruction(game_id: u64, players: [Pubkey; MATCH_PLAYERS])]
+pub struct ReportMatchResult<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED],
+        bump = role_registry.bump,
+        constraint = role_registry.is_initialized @ GameError::RoleRegistryNotInitialized
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+    
+    // Created once per game_id, so a result can never be reported twice
+    #[account(
+        init,
+        payer = game_server,
+        space = MATCH_SPACE,
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub match_record: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, &game_state.current_season.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, players[0].as_ref()],
+        bump
+    )]
+    pub player_score_0: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, players[1].as_ref()],
+        bump
+    )]
+    pub player_score_1: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, players[2].as_ref()],
+        bump
+    )]
+    pub player_score_2: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, players[3].as_ref()],
+        bump
+    )]
+    pub player_score_3: Box<Account<'info, PlayerScore>>,
+    
+    // Each seat's ticket proves the player held access when the table started and is consumed here
+    #[account(
+        mut,
+        close = ticket_payer_0,
+        seeds = [MATCH_TICKET_SEED, players[0].as_ref(), &match_ticket_0.nonce.to_le_bytes()],
+        bump = match_ticket_0.bump
+    )]
+    pub match_ticket_0: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        close = ticket_payer_1,
+        seeds = [MATCH_TICKET_SEED, players[1].as_ref(), &match_ticket_1.nonce.to_le_bytes()],
+        bump = match_ticket_1.bump
+    )]
+    pub match_ticket_1: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        close = ticket_payer_2,
+        seeds = [MATCH_TICKET_SEED, players[2].as_ref(), &match_ticket_2.nonce.to_le_bytes()],
+        bump = match_ticket_2.bump
+    )]
+    pub match_ticket_2: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        close = ticket_payer_3,
+        seeds = [MATCH_TICKET_SEED, players[3].as_ref(), &match_ticket_3.nonce.to_le_bytes()],
+        bump = match_ticket_3.bump
+    )]
+    pub match_ticket_3: Box<Account<'info, MatchTicket>>,
+    
+    #[account(mut, address = match_ticket_0.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_0: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_1.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_1: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_2.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_2: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_3.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_3: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = is_game_server(&role_registry, &game_server.key()) @ GameError::NotAuthorized
+    )]
+    pub game_server: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64, players: [Pubkey; MATCH_PLAYERS])]
+pub struct ProposeMatchResult<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = MATCH_SPACE,
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub match_record: Box<Account<'info, Match>>,
+    
+    // Each seat's ticket proves the player held access when the table started; it is consumed once the table attests
+    #[account(
+        seeds = [MATCH_TICKET_SEED, players[0].as_ref(), &match_ticket_0.nonce.to_le_bytes()],
+        bump = match_ticket_0.bump
+    )]
+    pub match_ticket_0: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        seeds = [MATCH_TICKET_SEED, players[1].as_ref(), &match_ticket_1.nonce.to_le_bytes()],
+        bump = match_ticket_1.bump
+    )]
+    pub match_ticket_1: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        seeds = [MATCH_TICKET_SEED, players[2].as_ref(), &match_ticket_2.nonce.to_le_bytes()],
+        bump = match_ticket_2.bump
+    )]
+    pub match_ticket_2: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        seeds = [MATCH_TICKET_SEED, players[3].as_ref(), &match_ticket_3.nonce.to_le_bytes()],
+        bump = match_ticket_3.bump
+    )]
+    pub match_ticket_3: Box<Account<'info, MatchTicket>>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct AttestMatchResult<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump = match_record.bump
+    )]
+    pub match_record: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, &game_state.current_season.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[0].as_ref()],
+        bump
+    )]
+    pub player_score_0: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[1].as_ref()],
+        bump
+    )]
+    pub player_score_1: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[2].as_ref()],
+        bump
+    )]
+    pub player_score_2: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[3].as_ref()],
+        bump
+    )]
+    pub player_score_3: Box<Account<'info, PlayerScore>>,
+    
+    // The proposal's tickets, closed when this attestation reaches the threshold
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[0].as_ref(), &match_record.ticket_nonces[0].to_le_bytes()],
+        bump = match_ticket_0.bump
+    )]
+    pub match_ticket_0: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[1].as_ref(), &match_record.ticket_nonces[1].to_le_bytes()],
+        bump = match_ticket_1.bump
+    )]
+    pub match_ticket_1: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[2].as_ref(), &match_record.ticket_nonces[2].to_le_bytes()],
+        bump = match_ticket_2.bump
+    )]
+    pub match_ticket_2: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[3].as_ref(), &match_record.ticket_nonces[3].to_le_bytes()],
+        bump = match_ticket_3.bump
+    )]
+    pub match_ticket_3: Box<Account<'info, MatchTicket>>,
+    
+    #[account(mut, address = match_ticket_0.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_0: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_1.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_1: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_2.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_2: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_3.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_3: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub attester: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct RejectMatchResult<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump = match_record.bump
+    )]
+    pub match_record: Box<Account<'info, Match>>,
+    
+    // The proposal's tickets, closed when this rejection contests the result
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[0].as_ref(), &match_record.ticket_nonces[0].to_le_bytes()],
+        bump = match_ticket_0.bump
+    )]
+    pub match_ticket_0: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[1].as_ref(), &match_record.ticket_nonces[1].to_le_bytes()],
+        bump = match_ticket_1.bump
+    )]
+    pub match_ticket_1: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[2].as_ref(), &match_record.ticket_nonces[2].to_le_bytes()],
+        bump = match_ticket_2.bump
+    )]
+    pub match_ticket_2: Box<Account<'info, MatchTicket>>,
+    
+    #[account(
+        mut,
+        seeds = [MATCH_TICKET_SEED, match_record.players[3].as_ref(), &match_record.ticket_nonces[3].to_le_bytes()],
+        bump = match_ticket_3.bump
+    )]
+    pub match_ticket_3: Box<Account<'info, MatchTicket>>,
+    
+    #[account(mut, address = match_ticket_0.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_0: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_1.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_1: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_2.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_2: SystemAccount<'info>,
+    #[account(mut, address = match_ticket_3.payer @ GameError::NotAuthorized)]
+    pub ticket_payer_3: SystemAccount<'info>,
+    
+    pub rejecter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CloseStaleMatchProposal<'info> {
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump = match_record.bump
+    )]
+    pub match_record: Box<Account<'info, Match>>,
+    
+    #[account(mut, address = match_record.reporter @ GameError::NotAuthorized)]
+    pub reporter: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DistributePrize<'info> {
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED],
+        bump = prize_vault.bump,
+        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+    )]
+    pub prize_vault: Account<'info, PrizeVault>,
+    
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_TOKEN_SEED],
+        bump = prize_vault.token_account_bump,
+        constraint = prize_vault_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint,
+        constraint = prize_vault_token_account.owner == prize_vault.key() @ GameError::InvalidTokenAccount
+    )]
+    pub prize_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == recipient.key() @ GameError::InvalidTokenAccount,
+        constraint = recipient_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_SCORE_SEED, recipient.key().as_ref()],
+        bump = player_score.bump,
+        constraint = player_score.player == recipient.key() @ GameError::NotAuthorized
+    )]
+    pub player_score: Account<'info, PlayerScore>,
+    
+    #[account(
+        seeds = [PLAYER_PASS_SEED, recipient.key().as_ref()],
+        bump = player_pass.bump,
+        constraint = player_pass.player == recipient.key() @ GameError::NotAuthorized
+    )]
+    pub player_pass: Account<'info, PlayerPass>,
     
     #[account(
-        mut,
-        seeds = [BURN_VAULT_SEED],
-        bump = burn_vault.bump
+        seeds = [PASS_TIER_SEED, &[player_pass.tier_id]],
+        bump = pass_tier.bump,
+        constraint = pass_tier.prize_eligible @ GameError::NotPrizeEligible
     )]
-    /// CHECK: This is the burn vault token account
-    pub burn_vault_token_account: UncheckedAccount<'info>,
+    pub pass_tier: Account<'info, PassTier>,
     
-    pub player: Signer<'info>,
-    pub wzn_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
-
-#[derive(Accounts)]
-pub struct CheckGameAccess<'info> {
     #[account(
         seeds = [GAME_STATE_SEED],
         bump = game_state.bump,
@@ -81,74 +1365,83 @@ pub struct CheckGameAccess<'info> {
     )]
     pub game_state: Account<'info, GameState>,
     
-    #[account(
-        seeds = [PLAYER_PASS_SEED, player.key().as_ref()],
-        bump = player_pass.bump,
-        constraint = player_pass.player == player.key() @ GameError::NotAuthorized
-    )]
-    pub player_pass: Account<'info, PlayerPass>,
-    
-    pub player: Signer<'info>,
+    pub recipient: Signer<'info>,
+    #[account(address = game_state.wzn_mint @ GameError::InvalidTokenMint)]
+    pub wzn_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = game_state.token_program @ GameError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-// Vault Management
+// Older scores are too short to deserialize, so the account is taken raw and checked in the handler
 #[derive(Accounts)]
-pub struct InitializeBurnVault<'info> {
+#[instruction(player: Pubkey)]
+pub struct MigratePlayerScore<'info> {
+    /// CHECK: Owner and discriminator are verified before the account is resized
     #[account(
-        init,
-        payer = authority,
-        space = 8 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 8,
-        seeds = [BURN_VAULT_SEED],
+        mut,
+        seeds = [PLAYER_SCORE_SEED, player.as_ref()],
         bump
     )]
-    pub burn_vault: Account<'info, BurnVault>,
+    pub player_score: UncheckedAccount<'info>,
     
-    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
+// Leaderboard
 #[derive(Accounts)]
-pub struct InitializePrizeVault<'info> {
+pub struct OpenLeaderboard<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+    )]
+    pub game_state: Account<'info, GameState>,
+    
     #[account(
         init,
-        payer = authority,
-        space = 8 + 1 + 8 + 8 + 8 + 1,
-        seeds = [PRIZE_VAULT_SEED],
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED, &game_state.current_season.to_le_bytes()],
         bump
     )]
-    pub prize_vault: Account<'info, PrizeVault>,
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
     
-    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct DepositToPrizeVault<'info> {
+#[instruction(player: Pubkey)]
+pub struct SyncMonthlyRank<'info> {
     #[account(
-        mut,
-        seeds = [PRIZE_VAULT_SEED],
-        bump = prize_vault.bump,
-        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
     )]
-    pub prize_vault: Account<'info, PrizeVault>,
+    pub game_state: Account<'info, GameState>,
     
     #[account(
         mut,
-        constraint = from_token_account.owner == authority.key() @ GameError::InvalidTokenAccount,
-        constraint = from_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+        seeds = [LEADERBOARD_SEED, &game_state.current_season.to_le_bytes()],
+        bump = leaderboard.bump
     )]
-    pub from_token_account: Account<'info, TokenAccount>,
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
     
     #[account(
         mut,
-        seeds = [PRIZE_VAULT_SEED],
-        bump = prize_vault.bump
+        seeds = [PLAYER_SCORE_SEED, player.as_ref()],
+        bump = player_score.bump
     )]
-    /// CHECK: This is the prize vault token account
-    pub prize_vault_token_account: UncheckedAccount<'info>,
-    
+    pub player_score: Account<'info, PlayerScore>,
+}
+
+// Match Disputes
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct DisputeMatch<'info> {
     #[account(
         seeds = [GAME_STATE_SEED],
         bump = game_state.bump,
@@ -156,212 +1449,241 @@ pub struct DepositToPrizeVault<'info> {
     )]
     pub game_state: Account<'info, GameState>,
     
-    pub authority: Signer<'info>,
-    pub wzn_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
-}
-
-// DAO Governance
-#[derive(Accounts)]
-pub struct InitializeDAO<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + 1 + 4 + 32 * 50 + 4 + 8 + 1 + 4 + 100 * 50, // Space for 50 members and 50 proposals
-        seeds = [DAO_GOVERNANCE_SEED],
-        bump
+        mut,
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump = match_record.bump
     )]
-    pub dao_governance: Account<'info, DAOGovernance>,
+    pub match_record: Box<Account<'info, Match>>,
     
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    pub disputer: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct CreateProposal<'info> {
+#[instruction(game_id: u64)]
+pub struct ResolveDispute<'info> {
     #[account(
-        mut,
-        seeds = [DAO_GOVERNANCE_SEED],
-        bump = dao_governance.bump,
-        constraint = dao_governance.is_initialized @ GameError::DAONotInitialized
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
     )]
-    pub dao_governance: Account<'info, DAOGovernance>,
+    pub game_state: Account<'info, GameState>,
     
-    pub proposer: Signer<'info>,
-}
-
-#[derive(Accounts)]
-pub struct VoteOnProposal<'info> {
     #[account(
-        mut,
-        seeds = [DAO_GOVERNANCE_SEED],
-        bump = dao_governance.bump,
-        constraint = dao_governance.is_initialized @ GameError::DAONotInitialized
+        seeds = [ROLE_REGISTRY_SEED],
+        bump = role_registry.bump,
+        constraint = role_registry.is_initialized @ GameError::RoleRegistryNotInitialized
     )]
-    pub dao_governance: Account<'info, DAOGovernance>,
+    pub role_registry: Account<'info, RoleRegistry>,
     
-    pub voter: Signer<'info>,
-}
-
-#[derive(Accounts)]
-pub struct ExecuteProposal<'info> {
     #[account(
         mut,
-        seeds = [DAO_GOVERNANCE_SEED],
-        bump = dao_governance.bump,
-        constraint = dao_governance.is_initialized @ GameError::DAONotInitialized
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump = match_record.bump
     )]
-    pub dao_governance: Account<'info, DAOGovernance>,
+    pub match_record: Box<Account<'info, Match>>,
     
     #[account(
         mut,
-        seeds = [BURN_VAULT_SEED],
-        bump = burn_vault.bump,
-        constraint = burn_vault.is_initialized @ GameError::BurnVaultNotInitialized
+        seeds = [LEADERBOARD_SEED, &match_record.season.to_le_bytes()],
+        bump = leaderboard.bump
     )]
-    pub burn_vault: Account<'info, BurnVault>,
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
     
+    // Only needed when the match's season has already been closed by monthly_reset
     #[account(
         mut,
-        seeds = [PRIZE_VAULT_SEED],
-        bump = prize_vault.bump,
-        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+        seeds = [SEASON_SEED, &match_record.season.to_le_bytes()],
+        bump = season_record.bump
     )]
-    pub prize_vault: Account<'info, PrizeVault>,
+    pub season_record: Option<Box<Account<'info, Season>>>,
     
+    // A contested result was never applied, so its seats may not have a score yet
     #[account(
-        mut,
-        seeds = [GAME_STATE_SEED],
-        bump = game_state.bump,
-        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+        init_if_needed,
+        payer = arbiter,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[0].as_ref()],
+        bump
     )]
-    pub game_state: Account<'info, GameState>,
+    pub player_score_0: Box<Account<'info, PlayerScore>>,
     
-    pub executor: Signer<'info>,
-    pub token_program: Program<'info, Token>,
-}
-
-// Emergency Recovery
-#[derive(Accounts)]
-pub struct InitializeEmergencyRecovery<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + 1 + 4 + 32 * 20 + 4 + 8 + 8 + 1 + 1 + 8, // Space for 20 backup members
-        seeds = [EMERGENCY_RECOVERY_SEED],
+        init_if_needed,
+        payer = arbiter,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[1].as_ref()],
         bump
     )]
-    pub emergency_recovery: Account<'info, EmergencyRecovery>,
+    pub player_score_1: Box<Account<'info, PlayerScore>>,
     
-    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[2].as_ref()],
+        bump
+    )]
+    pub player_score_2: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = PLAYER_SCORE_SPACE,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[3].as_ref()],
+        bump
+    )]
+    pub player_score_3: Box<Account<'info, PlayerScore>>,
+    
+    #[account(
+        mut,
+        constraint = is_arbiter(&role_registry, &game_state, &arbiter.key()) @ GameError::NotAuthorized
+    )]
+    pub arbiter: Signer<'info>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct EmergencyUnlock<'info> {
+#[instruction(game_id: u64)]
+pub struct FinalizeMatch<'info> {
     #[account(
-        mut,
-        seeds = [EMERGENCY_RECOVERY_SEED],
-        bump = emergency_recovery.bump,
-        constraint = emergency_recovery.is_initialized @ GameError::EmergencyRecoveryNotInitialized
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
     )]
-    pub emergency_recovery: Account<'info, EmergencyRecovery>,
+    pub game_state: Account<'info, GameState>,
     
     #[account(
         mut,
-        seeds = [BURN_VAULT_SEED],
-        bump = burn_vault.bump,
-        constraint = burn_vault.is_initialized @ GameError::BurnVaultNotInitialized
+        seeds = [MATCH_SEED, &game_id.to_le_bytes()],
+        bump = match_record.bump
     )]
-    pub burn_vault: Account<'info, BurnVault>,
+    pub match_record: Box<Account<'info, Match>>,
     
     #[account(
         mut,
-        seeds = [BURN_VAULT_SEED],
-        bump = burn_vault.bump
+        seeds = [PLAYER_SCORE_SEED, match_record.players[0].as_ref()],
+        bump = player_score_0.bump
     )]
-    /// CHECK: This is the burn vault token account
-    pub burn_vault_token_account: UncheckedAccount<'info>,
+    pub player_score_0: Box<Account<'info, PlayerScore>>,
     
     #[account(
         mut,
-        constraint = recipient_token_account.owner == recipient.key() @ GameError::InvalidTokenAccount,
-        constraint = recipient_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+        seeds = [PLAYER_SCORE_SEED, match_record.players[1].as_ref()],
+        bump = player_score_1.bump
     )]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub player_score_1: Box<Account<'info, PlayerScore>>,
     
     #[account(
-        seeds = [GAME_STATE_SEED],
-        bump = game_state.bump,
-        constraint = game_state.is_initialized @ GameError::GameNotInitialized
+        mut,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[2].as_ref()],
+        bump = player_score_2.bump
     )]
-    pub game_state: Account<'info, GameState>,
+    pub player_score_2: Box<Account<'info, PlayerScore>>,
     
-    pub recipient: Signer<'info>,
-    pub wzn_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    #[account(
+        mut,
+        seeds = [PLAYER_SCORE_SEED, match_record.players[3].as_ref()],
+        bump = player_score_3.bump
+    )]
+    pub player_score_3: Box<Account<'info, PlayerScore>>,
 }
 
-// Player Score Management
+// Account Cleanup
 #[derive(Accounts)]
-pub struct UpdatePlayerScore<'info> {
+pub struct ClosePlayerPass<'info> {
     #[account(
-        mut,
-        init_if_needed,
-        payer = player,
-        space = 8 + 1 + 32 + 4 + 4 + 4 + 4 + 4 + 8 + 8,
-        seeds = [PLAYER_SCORE_SEED, player.key().as_ref()],
-        bump
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
     )]
-    pub player_score: Account<'info, PlayerScore>,
+    pub game_state: Account<'info, GameState>,
     
     #[account(
+        mut,
+        close = rent_payer,
         seeds = [PLAYER_PASS_SEED, player.key().as_ref()],
         bump = player_pass.bump,
         constraint = player_pass.player == player.key() @ GameError::NotAuthorized
     )]
     pub player_pass: Account<'info, PlayerPass>,
     
+    #[account(mut, address = player_pass.rent_payer @ GameError::NotAuthorized)]
+    pub rent_payer: SystemAccount<'info>,
+    
     pub player: Signer<'info>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    #[account(mut)]
+    pub pass_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Pass token authority PDA, verified when the pass token is synced
+    pub pass_authority: Option<UncheckedAccount<'info>>,
+    pub pass_token_program: Option<Program<'info, Token2022>>,
 }
 
 #[derive(Accounts)]
-pub struct DistributePrize<'info> {
+#[instruction(player: Pubkey)]
+pub struct CloseInactivePlayerPass<'info> {
     #[account(
-        mut,
-        seeds = [PRIZE_VAULT_SEED],
-        bump = prize_vault.bump,
-        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
     )]
-    pub prize_vault: Account<'info, PrizeVault>,
+    pub game_state: Account<'info, GameState>,
     
     #[account(
         mut,
-        seeds = [PRIZE_VAULT_SEED],
-        bump = prize_vault.bump
+        close = rent_payer,
+        seeds = [PLAYER_PASS_SEED, player.as_ref()],
+        bump = player_pass.bump
     )]
-    /// CHECK: This is the prize vault token account
-    pub prize_vault_token_account: UncheckedAccount<'info>,
+    pub player_pass: Account<'info, PlayerPass>,
     
+    #[account(mut, address = player_pass.rent_payer @ GameError::NotAuthorized)]
+    pub rent_payer: SystemAccount<'info>,
+    #[account(mut)]
+    pub pass_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Pass token authority PDA, verified when the pass token is synced
+    pub pass_authority: Option<UncheckedAccount<'info>>,
+    pub pass_token_program: Option<Program<'info, Token2022>>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePlayerScore<'info> {
     #[account(
-        mut,
-        constraint = recipient_token_account.owner == recipient.key() @ GameError::InvalidTokenAccount,
-        constraint = recipient_token_account.mint == game_state.wzn_mint @ GameError::InvalidTokenMint
+        seeds = [GAME_STATE_SEED],
+        bump = game_state.bump,
+        constraint = game_state.is_initialized @ GameError::GameNotInitialized
     )]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub game_state: Account<'info, GameState>,
     
     #[account(
         mut,
-        seeds = [PLAYER_SCORE_SEED, recipient.key().as_ref()],
+        close = rent_payer,
+        seeds = [PLAYER_SCORE_SEED, player.key().as_ref()],
         bump = player_score.bump,
-        constraint = player_score.player == recipient.key() @ GameError::NotAuthorized
+        constraint = player_score.player == player.key() @ GameError::NotAuthorized
     )]
     pub player_score: Account<'info, PlayerScore>,
     
+    /// CHECK: The player's pass PDA, which may already be closed; checked in the handler
+    #[account(
+        seeds = [PLAYER_PASS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_pass: UncheckedAccount<'info>,
+    
+    #[account(mut, address = player_score.rent_payer @ GameError::NotAuthorized)]
+    pub rent_payer: SystemAccount<'info>,
+    
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct CloseInactivePlayerScore<'info> {
     #[account(
         seeds = [GAME_STATE_SEED],
         bump = game_state.bump,
@@ -369,9 +1691,23 @@ pub struct DistributePrize<'info> {
     )]
     pub game_state: Account<'info, GameState>,
     
-    pub recipient: Signer<'info>,
-    pub wzn_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [PLAYER_SCORE_SEED, player.as_ref()],
+        bump = player_score.bump
+    )]
+    pub player_score: Account<'info, PlayerScore>,
+    
+    /// CHECK: The player's pass PDA, which may already be closed; checked in the handler
+    #[account(
+        seeds = [PLAYER_PASS_SEED, player.as_ref()],
+        bump
+    )]
+    pub player_pass: UncheckedAccount<'info>,
+    
+    #[account(mut, address = player_score.rent_payer @ GameError::NotAuthorized)]
+    pub rent_payer: SystemAccount<'info>,
 }
 
 // Monthly Reset
@@ -385,5 +1721,44 @@ pub struct MonthlyReset<'info> {
     )]
     pub game_state: Account<'info, GameState>,
     
+    #[account(
+        seeds = [LEADERBOARD_SEED, &game_state.current_season.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 4 + 8 + 8 + 4 + (32 + 4 + 4) * LEADERBOARD_SIZE + 8 + 8,
+        seeds = [SEASON_SEED, &game_state.current_season.to_le_bytes()],
+        bump
+    )]
+    pub season_record: Box<Account<'info, Season>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED, &(game_state.current_season + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_leaderboard: Box<Account<'info, Leaderboard>>,
+    
+    #[account(
+        seeds = [PRIZE_VAULT_SEED],
+        bump = prize_vault.bump,
+        constraint = prize_vault.is_initialized @ GameError::PrizeVaultNotInitialized
+    )]
+    pub prize_vault: Account<'info, PrizeVault>,
+    
+    #[account(
+        seeds = [PRIZE_VAULT_TOKEN_SEED],
+        bump = prize_vault.token_account_bump
+    )]
+    pub prize_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
     pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 } 
\ No newline at end of file
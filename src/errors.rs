@@ -82,4 +82,193 @@ pub enum GameError {
     
     #[msg("Invalid emergency unlock amount")]
     InvalidEmergencyUnlockAmount,
-} 
\ No newline at end of file
+    
+    #[msg("Invalid number of pass months")]
+    InvalidPassMonths,
+    
+    #[msg("Pass would exceed the maximum prepaid horizon")]
+    PrepaidHorizonExceeded,
+    
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    
+    #[msg("Invalid pass tier configuration")]
+    InvalidPassTier,
+    
+    #[msg("Pass tier is not available for purchase")]
+    PassTierInactive,
+    
+    #[msg("Active pass belongs to a different tier")]
+    PassTierMismatch,
+    
+    #[msg("Pass tier does not grant access to this game mode")]
+    GameModeNotAllowed,
+    
+    #[msg("Pass tier is not eligible for prizes")]
+    NotPrizeEligible,
+    
+    #[msg("Fee config not initialized")]
+    FeeConfigNotInitialized,
+    
+    #[msg("Fee payer is not approved for the current fee mode")]
+    FeePayerNotApproved,
+    
+    #[msg("Invalid fee mode")]
+    InvalidFeeMode,
+    
+    #[msg("Too many approved sponsors")]
+    TooManySponsors,
+    
+    #[msg("Free game quota exhausted for this period")]
+    FreeQuotaExhausted,
+    
+    #[msg("Match ticket expired")]
+    MatchTicketExpired,
+    
+    #[msg("Match ticket still valid")]
+    MatchTicketStillValid,
+    
+    #[msg("Role registry not initialized")]
+    RoleRegistryNotInitialized,
+    
+    #[msg("Too many role members")]
+    TooManyRoleMembers,
+    
+    #[msg("Player pass is suspended")]
+    PassSuspended,
+    
+    #[msg("Player pass is not suspended")]
+    PassNotSuspended,
+    
+    #[msg("Invalid suspension end time")]
+    InvalidSuspensionEnd,
+    
+    #[msg("Invalid token program")]
+    InvalidTokenProgram,
+    
+    #[msg("Burn share must be at most 10000 basis points")]
+    InvalidBurnBps,
+    
+    #[msg("Invalid burn routing table")]
+    InvalidBurnRoutes,
+    
+    #[msg("Recovery vault not initialized")]
+    RecoveryVaultNotInitialized,
+    
+    #[msg("Price feed not initialized")]
+    PriceFeedNotInitialized,
+    
+    #[msg("Price feed does not match the configured feed")]
+    InvalidPriceFeed,
+    
+    #[msg("Pegged pricing requires the price feed account")]
+    PriceFeedRequired,
+    
+    #[msg("Invalid price")]
+    InvalidPrice,
+    
+    #[msg("Invalid pricing configuration")]
+    InvalidPricingConfig,
+    
+    #[msg("Price steps must take effect in the future")]
+    InvalidPriceStep,
+    
+    #[msg("Too many scheduled price steps")]
+    TooManyPriceSteps,
+    
+    #[msg("Player pass is still running, suspended or in use")]
+    PassStillInUse,
+    
+    #[msg("Account has not been inactive long enough to close")]
+    AccountNotInactive,
+    
+    #[msg("Pass token already created")]
+    PassTokenExists,
+    
+    #[msg("Pass token accounts are required to keep the pass token in sync")]
+    PassTokenAccountsRequired,
+    
+    #[msg("Invalid pass token account")]
+    InvalidPassToken,
+    
+    #[msg("A player can only take one seat at the table")]
+    DuplicateMatchPlayer,
+    
+    #[msg("Invalid match result")]
+    InvalidMatchResult,
+    
+    #[msg("Match ticket was issued for a different game mode")]
+    TicketModeMismatch,
+    
+    #[msg("Signer is not seated at this match")]
+    NotSeatedPlayer,
+    
+    #[msg("Seat has already attested this match")]
+    AlreadyAttested,
+    
+    #[msg("Match is not awaiting attestation")]
+    MatchNotAwaitingAttestation,
+    
+    #[msg("Attestation threshold must be a majority of the table")]
+    InvalidAttestationThreshold,
+    
+    #[msg("Match result is not pending")]
+    MatchNotPending,
+    
+    #[msg("Match result is not disputed")]
+    MatchNotDisputed,
+    
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+    
+    #[msg("Invalid dispute window")]
+    InvalidDisputeWindow,
+    
+    #[msg("Player score already has the current layout")]
+    ScoreAlreadyMigrated,
+    
+    #[msg("Pass tier account is required to play on a pass")]
+    PassTierRequired,
+    
+    #[msg("The routing table sends a leg to a vault that was not provided")]
+    RouteVaultMissing,
+    
+    #[msg("Not enough undistributed funds in the prize vault")]
+    InsufficientPrizeFunds,
+    
+    #[msg("Amount exceeds the prizes awarded to this player")]
+    PrizeNotAwarded,
+    
+    #[msg("Another emergency unlock is awaiting approval")]
+    EmergencyUnlockPending,
+    
+    #[msg("Emergency unlock has not been approved by the backup members' quorum")]
+    EmergencyUnlockNotApproved,
+    
+    #[msg("The attestation window for this proposal has closed")]
+    AttestationWindowClosed,
+    
+    #[msg("The proposal can still be attested")]
+    AttestationWindowOpen,
+    
+    #[msg("Player still has matches open to dispute")]
+    ScoreHasOpenMatches,
+    
+    #[msg("Player has awarded prizes left to claim")]
+    UnclaimedPrizes,
+    
+    #[msg("The match's season has closed; its season record must be provided")]
+    SeasonRecordRequired,
+    
+    #[msg("The match's season has closed; a contested result can only be voided")]
+    MatchSeasonClosed,
+    
+    #[msg("The recipient's player score must be provided")]
+    PlayerScoreRequired,
+    
+    #[msg("The player's pass must be provided")]
+    PlayerPassRequired,
+}
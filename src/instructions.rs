@@ -1,15 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token;
+use anchor_spl::token_2022::{self, spl_token_2022};
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
+use spl_token_2022::extension::{metadata_pointer, ExtensionType, StateWithExtensions};
+use spl_token_metadata_interface::state::Field;
 
 use crate::errors::GameError;
 use crate::state::*;
 use crate::accounts::*;
+use crate::rating;
 
 // Game Management Instructions
-pub fn initialize_game(ctx: Context<InitializeGame>, monthly_pass_cost: u64) -> Result<()> {
+pub fn initialize_game(ctx: Context<InitializeGame>, monthly_pass_cost: u64, max_prepaid_months: u8) -> Result<()> {
     let game_state = &mut ctx.accounts.game_state;
     let clock = Clock::get()?;
 
+    require!(
+        max_prepaid_months > 0 && max_prepaid_months <= MAX_PREPAID_MONTHS_LIMIT,
+        GameError::InvalidPassMonths
+    );
+
     game_state.bump = ctx.bumps.game_state;
     game_state.authority = ctx.accounts.authority.key();
     game_state.wzn_mint = ctx.accounts.wzn_mint.key();
@@ -19,20 +32,177 @@ pub fn initialize_game(ctx: Context<InitializeGame>, monthly_pass_cost: u64) ->
     game_state.total_prizes_distributed = 0;
     game_state.last_monthly_reset = clock.unix_timestamp;
     game_state.emergency_mode = false;
+    game_state.token_program = *ctx.accounts.wzn_mint.to_account_info().owner;
+    game_state.max_prepaid_months = max_prepaid_months;
+    game_state.free_games_per_period = DEFAULT_FREE_GAMES_PER_PERIOD;
+    game_state.burn_bps = DEFAULT_BURN_BPS;
+    game_state.total_destroyed = 0;
+    game_state.total_locked = 0;
+    game_state.burn_routes = default_burn_routes();
+    game_state.pricing_mode = PricingMode::Fixed;
+    game_state.price_feed = Pubkey::default();
+    game_state.reference_pass_cost = 0;
+    game_state.max_price_age = 0;
+    game_state.max_price_confidence_bps = 0;
+    game_state.min_pass_cost = 0;
+    game_state.max_pass_cost = 0;
+    game_state.price_schedule = Vec::new();
+    game_state.inactivity_close_months = DEFAULT_INACTIVITY_CLOSE_MONTHS;
+    game_state.attestation_threshold = DEFAULT_ATTESTATION_THRESHOLD;
+    game_state.dispute_window = DEFAULT_DISPUTE_WINDOW;
+    game_state.current_season = 1;
+    game_state.season_start_burned = 0;
+    game_state.provisional_games = DEFAULT_PROVISIONAL_GAMES;
+    game_state.rating_decay_weeks = DEFAULT_RATING_DECAY_WEEKS;
+    game_state.rating_floor = DEFAULT_RATING_FLOOR;
 
     msg!("Game initialized with monthly pass cost: {}", monthly_pass_cost);
+    msg!("Maximum prepaid months: {}", max_prepaid_months);
+    Ok(())
+}
+
+// Fee Management Instructions
+pub fn initialize_fee_config(ctx: Context<InitializeFeeConfig>, fee_mode: FeeMode, approved_sponsors: Vec<Pubkey>) -> Result<()> {
+    let fee_config = &mut ctx.accounts.fee_config;
+
+    require!(approved_sponsors.len() <= MAX_FEE_SPONSORS, GameError::TooManySponsors);
+
+    fee_config.bump = ctx.bumps.fee_config;
+    fee_config.fee_mode = fee_mode;
+    fee_config.approved_sponsors = approved_sponsors;
+    fee_config.is_initialized = true;
+
+    msg!("Fee config initialized with {} approved sponsors", fee_config.approved_sponsors.len());
+    Ok(())
+}
+
+// Pricing Instructions
+pub fn initialize_price_feed(ctx: Context<InitializePriceFeed>, updater: Pubkey) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+
+    price_feed.bump = ctx.bumps.price_feed;
+    price_feed.updater = updater;
+    price_feed.price = 0;
+    price_feed.confidence = 0;
+    price_feed.expo = 0;
+    price_feed.publish_time = 0;
+    price_feed.is_initialized = true;
+
+    msg!("Price feed initialized with updater: {}", updater);
     Ok(())
 }
 
-pub fn burn_to_play(ctx: Context<BurnToPlay>, amount: u64) -> Result<()> {
+pub fn update_price_feed(ctx: Context<UpdatePriceFeed>, price: u64, confidence: u64, expo: i32) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+
+    require!(price > 0, GameError::InvalidPrice);
+
+    price_feed.price = price;
+    price_feed.confidence = confidence;
+    price_feed.expo = expo;
+    price_feed.publish_time = Clock::get()?.unix_timestamp;
+
+    msg!("Price updated: {} +/- {} (expo {})", price, confidence, expo);
+    Ok(())
+}
+
+pub fn configure_pricing(
+    ctx: Context<ConfigurePricing>,
+    pricing_mode: PricingMode,
+    reference_pass_cost: u64,
+    max_price_age: i64,
+    max_price_confidence_bps: u16,
+    min_pass_cost: u64,
+    max_pass_cost: u64,
+) -> Result<()> {
     let game_state = &mut ctx.accounts.game_state;
-    let burn_vault = &mut ctx.accounts.burn_vault;
+
+    if pricing_mode == PricingMode::Pegged {
+        require!(ctx.accounts.price_feed.is_some(), GameError::PriceFeedRequired);
+        require!(reference_pass_cost > 0, GameError::InvalidPricingConfig);
+        require!(max_price_age > 0, GameError::InvalidPricingConfig);
+        require!(max_price_confidence_bps as u64 <= BPS_DENOMINATOR, GameError::InvalidPricingConfig);
+        require!(min_pass_cost <= max_pass_cost && max_pass_cost > 0, GameError::InvalidPricingConfig);
+    }
+
+    game_state.pricing_mode = pricing_mode;
+    if let Some(price_feed) = &ctx.accounts.price_feed {
+        game_state.price_feed = price_feed.key();
+    }
+    game_state.reference_pass_cost = reference_pass_cost;
+    game_state.max_price_age = max_price_age;
+    game_state.max_price_confidence_bps = max_price_confidence_bps;
+    game_state.min_pass_cost = min_pass_cost;
+    game_state.max_pass_cost = max_pass_cost;
+
+    msg!("Pricing configured: reference cost {}, WZN bounds {}..={}", reference_pass_cost, min_pass_cost, max_pass_cost);
+    Ok(())
+}
+
+// Monthly pass cost in WZN for this purchase. Falls back to the fixed cost when the feed is unusable.
+fn current_monthly_pass_cost(game_state: &GameState, price_feed: Option<&PriceFeed>, decimals: u8, now: i64) -> Result<u64> {
+    match game_state.pricing_mode {
+        PricingMode::Fixed => Ok(scheduled_pass_cost(game_state, now)),
+        PricingMode::Pegged => {
+            let feed = price_feed.ok_or(GameError::PriceFeedRequired)?;
+            match pegged_pass_cost(game_state, feed, decimals, now) {
+                Some(cost) => Ok(cost),
+                None => {
+                    msg!("Price feed stale or uncertain, using fixed pass cost");
+                    Ok(scheduled_pass_cost(game_state, now))
+                }
+            }
+        }
+    }
+}
+
+pub fn configure_pass_tier(ctx: Context<ConfigurePassTier>, tier_id: u8, config: PassTierConfig) -> Result<()> {
+    let pass_tier = &mut ctx.accounts.pass_tier;
+
+    require!(config.cost_bps > 0, GameError::InvalidPassTier);
+    require!(config.duration > 0, GameError::InvalidPassTier);
+
+    pass_tier.bump = ctx.bumps.pass_tier;
+    pass_tier.tier_id = tier_id;
+    pass_tier.cost_bps = config.cost_bps;
+    pass_tier.duration = config.duration;
+    pass_tier.ranked_access = config.ranked_access;
+    pass_tier.tournament_entry = config.tournament_entry;
+    pass_tier.prize_eligible = config.prize_eligible;
+    pass_tier.is_active = config.is_active;
+
+    msg!("Pass tier {} configured: {} bps per {} seconds", tier_id, config.cost_bps, config.duration);
+    Ok(())
+}
+
+pub fn burn_to_play(ctx: Context<BurnToPlay>, tier_id: u8, periods: u8) -> Result<()> {
     let player_pass = &mut ctx.accounts.player_pass;
-    let clock = Clock::get()?;
 
-    // Validate burn amount
-    require!(amount >= game_state.monthly_pass_cost, GameError::InvalidAmount);
-    require!(amount >= MINIMUM_BURN_AMOUNT, GameError::InvalidAmount);
+    // Initialize player pass if needed
+    if player_pass.player == Pubkey::default() {
+        player_pass.player = ctx.accounts.player.key();
+        player_pass.bump = ctx.bumps.player_pass;
+        player_pass.total_passes_purchased = 0;
+        player_pass.total_tokens_burned = 0;
+        player_pass.rent_payer = ctx.accounts.fee_payer.key();
+    }
+    player_pass.sponsor = None;
+
+    let now = Clock::get()?.unix_timestamp;
+    let monthly_cost = current_monthly_pass_cost(
+        &ctx.accounts.game_state,
+        ctx.accounts.price_feed.as_deref(),
+        ctx.accounts.wzn_mint.decimals,
+        now,
+    )?;
+    let amount = apply_pass_purchase(
+        &mut ctx.accounts.game_state,
+        &ctx.accounts.pass_tier,
+        player_pass,
+        periods,
+        monthly_cost,
+        now,
+    )?;
 
     // Check if player has sufficient tokens
     require!(
@@ -40,53 +210,738 @@ pub fn burn_to_play(ctx: Context<BurnToPlay>, amount: u64) -> Result<()> {
         GameError::InsufficientTokens
     );
 
-    // Initialize player pass if needed
+    // Burn and lock the player's tokens according to the configured split
+    let accounts = &mut *ctx.accounts;
+    settle_pass_payment(
+        &mut accounts.game_state,
+        RouteVaults {
+            burn_vault: &mut accounts.burn_vault,
+            burn_vault_token_account: &mut accounts.burn_vault_token_account,
+            recovery: accounts.recovery_vault.as_deref_mut().zip(accounts.recovery_vault_token_account.as_mut()),
+            prize: accounts.prize_vault.as_deref_mut().zip(accounts.prize_vault_token_account.as_mut()),
+        },
+        &accounts.token_program,
+        &accounts.player_token_account,
+        &accounts.wzn_mint,
+        accounts.player.to_account_info(),
+        amount,
+    )?;
+
+    sync_optional_pass_token(
+        &ctx.accounts.player_pass,
+        &ctx.accounts.pass_mint,
+        &ctx.accounts.pass_token_account,
+        &ctx.accounts.pass_authority,
+        &ctx.accounts.pass_token_program,
+        now,
+    )?;
+
+    msg!("Player burned {} WZN tokens for {} period(s) of tier {} pass", amount, periods, tier_id);
+    msg!("Pass valid until: {}", ctx.accounts.player_pass.pass_end_time);
+    Ok(())
+}
+
+pub fn burn_to_play_for(ctx: Context<BurnToPlayFor>, tier_id: u8, periods: u8, beneficiary: Pubkey) -> Result<()> {
+    let player_pass = &mut ctx.accounts.player_pass;
+
+    // Initialize the beneficiary's pass if needed
     if player_pass.player == Pubkey::default() {
-        player_pass.player = ctx.accounts.player.key();
+        player_pass.player = beneficiary;
         player_pass.bump = ctx.bumps.player_pass;
         player_pass.total_passes_purchased = 0;
         player_pass.total_tokens_burned = 0;
+        player_pass.rent_payer = ctx.accounts.sponsor.key();
     }
+    player_pass.sponsor = Some(ctx.accounts.sponsor.key());
+
+    let now = Clock::get()?.unix_timestamp;
+    let monthly_cost = current_monthly_pass_cost(
+        &ctx.accounts.game_state,
+        ctx.accounts.price_feed.as_deref(),
+        ctx.accounts.wzn_mint.decimals,
+        now,
+    )?;
+    let amount = apply_pass_purchase(
+        &mut ctx.accounts.game_state,
+        &ctx.accounts.pass_tier,
+        player_pass,
+        periods,
+        monthly_cost,
+        now,
+    )?;
+
+    // Check if sponsor has sufficient tokens
+    require!(
+        ctx.accounts.sponsor_token_account.amount >= amount,
+        GameError::InsufficientTokens
+    );
+
+    // Burn and lock the sponsor's tokens according to the configured split
+    let accounts = &mut *ctx.accounts;
+    settle_pass_payment(
+        &mut accounts.game_state,
+        RouteVaults {
+            burn_vault: &mut accounts.burn_vault,
+            burn_vault_token_account: &mut accounts.burn_vault_token_account,
+            recovery: accounts.recovery_vault.as_deref_mut().zip(accounts.recovery_vault_token_account.as_mut()),
+            prize: accounts.prize_vault.as_deref_mut().zip(accounts.prize_vault_token_account.as_mut()),
+        },
+        &accounts.token_program,
+        &accounts.sponsor_token_account,
+        &accounts.wzn_mint,
+        accounts.sponsor.to_account_info(),
+        amount,
+    )?;
+
+    sync_optional_pass_token(
+        &ctx.accounts.player_pass,
+        &ctx.accounts.pass_mint,
+        &ctx.accounts.pass_token_account,
+        &ctx.accounts.pass_authority,
+        &ctx.accounts.pass_token_program,
+        now,
+    )?;
+
+    msg!("Sponsor {} burned {} WZN tokens for {} period(s) of tier {} pass", ctx.accounts.sponsor.key(), amount, periods, tier_id);
+    msg!("Pass for {} valid until: {}", beneficiary, ctx.accounts.player_pass.pass_end_time);
+    Ok(())
+}
+
+// Prices the purchase, extends the pass and records the burn. Returns the WZN amount to burn.
+// A running pass can only be extended on its own tier: changing tier, including a casual to ranked
+// upgrade, fails with PassTierMismatch until the current pass has expired.
+fn apply_pass_purchase(
+    game_state: &mut GameState,
+    pass_tier: &PassTier,
+    player_pass: &mut PlayerPass,
+    periods: u8,
+    monthly_cost: u64,
+    now: i64,
+) -> Result<u64> {
+    require!(periods > 0, GameError::InvalidPassMonths);
+
+    // Validate burn amount
+    let period_cost = calculate_tier_cost(monthly_cost, pass_tier.cost_bps)
+        .ok_or(GameError::MathOverflow)?;
+    let amount = period_cost
+        .checked_mul(periods as u64)
+        .ok_or(GameError::MathOverflow)?;
+    require!(amount >= MINIMUM_BURN_AMOUNT, GameError::InvalidAmount);
+
+    // Extend from the current expiry if the pass is still running
+    let base = pass_extension_base(player_pass, now);
+    if base != now {
+        require!(player_pass.tier_id == pass_tier.tier_id, GameError::PassTierMismatch);
+    }
+    let purchased_duration = pass_tier
+        .duration
+        .checked_mul(periods as i64)
+        .ok_or(GameError::MathOverflow)?;
+    let new_end_time = base
+        .checked_add(purchased_duration)
+        .ok_or(GameError::MathOverflow)?;
+    require!(
+        new_end_time - now <= game_state.max_prepaid_months as i64 * MONTHLY_SECONDS,
+        GameError::PrepaidHorizonExceeded
+    );
 
     // Update player pass
-    player_pass.pass_start_time = clock.unix_timestamp;
-    player_pass.pass_end_time = clock.unix_timestamp + MONTHLY_SECONDS;
+    if base == now {
+        player_pass.pass_start_time = now;
+    }
+    player_pass.pass_end_time = new_end_time;
     player_pass.is_active = true;
-    player_pass.total_passes_purchased += 1;
+    player_pass.tier_id = pass_tier.tier_id;
+    player_pass.total_passes_purchased += (purchased_duration / MONTHLY_SECONDS) as u32;
     player_pass.total_tokens_burned += amount;
+    player_pass.last_activity = now;
 
     // Update game state
     game_state.total_burned += amount;
 
-    // Update burn vault
-    burn_vault.total_locked += amount;
+    Ok(amount)
+}
+
+// Destination vaults for the locked share of a purchase
+struct RouteVaults<'a, 'info> {
+    burn_vault: &'a mut BurnVault,
+    burn_vault_token_account: &'a mut InterfaceAccount<'info, TokenAccount>,
+    // Only required when the routing table sends a leg there
+    recovery: Option<(&'a mut RecoveryVault, &'a mut InterfaceAccount<'info, TokenAccount>)>,
+    prize: Option<(&'a mut PrizeVault, &'a mut InterfaceAccount<'info, TokenAccount>)>,
+}
+
+// Destroys the burn share of a purchase and routes the remainder across the vault routing table
+fn settle_pass_payment<'info>(
+    game_state: &mut GameState,
+    mut vaults: RouteVaults<'_, 'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let (destroyed, locked) = split_burn_amount(amount, game_state.burn_bps)
+        .ok_or(GameError::MathOverflow)?;
+
+    if destroyed > 0 {
+        let burn_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: from.to_account_info(),
+                authority: authority.clone(),
+            },
+        );
+
+        token_interface::burn(burn_ctx, destroyed)?;
+        game_state.total_destroyed += destroyed;
+    }
+
+    let legs = split_by_routes(locked, &game_state.burn_routes).ok_or(GameError::InvalidBurnRoutes)?;
+    for (destination, leg) in legs {
+        if leg == 0 {
+            continue;
+        }
+
+        let deposit = |vault_token_account: &mut InterfaceAccount<'info, TokenAccount>| {
+            deposit_into_vault(token_program, from, vault_token_account, mint, authority.clone(), leg)
+        };
+
+        // Record what actually arrived
+        let received = match destination {
+            VaultDestination::BurnVault => {
+                let received = deposit(&mut *vaults.burn_vault_token_account)?;
+                vaults.burn_vault.total_locked += received;
+                received
+            }
+            VaultDestination::RecoveryVault => {
+                let (vault, token_account) = vaults.recovery.as_mut().ok_or(GameError::RouteVaultMissing)?;
+                require_keys_eq!(token_account.key(), vault.token_account, GameError::InvalidTokenAccount);
+                let received = deposit(token_account)?;
+                vault.total_received += received;
+                received
+            }
+            VaultDestination::PrizeVault => {
+                let (vault, token_account) = vaults.prize.as_mut().ok_or(GameError::RouteVaultMissing)?;
+                require_keys_eq!(token_account.key(), vault.token_account, GameError::InvalidTokenAccount);
+                let received = deposit(token_account)?;
+                vault.total_deposited += received;
+                received
+            }
+        };
+        game_state.total_locked += received;
+
+        msg!("Routed {} WZN to {:?}", received, destination);
+    }
+
+    msg!("Destroyed {} WZN, locked {} WZN", destroyed, locked);
+    Ok(())
+}
+
+// Moves WZN into a vault and returns what actually arrived, net of any Token-2022 transfer fee
+fn deposit_into_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    vault_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let balance_before = vault_token_account.amount;
 
-    // Transfer tokens to burn vault
     let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.player_token_account.to_account_info(),
-            to: ctx.accounts.burn_vault_token_account.to_account_info(),
-            authority: ctx.accounts.player.to_account_info(),
+        token_program.to_account_info(),
+        TransferChecked {
+            from: from.to_account_info(),
+            mint: mint.to_account_info(),
+            to: vault_token_account.to_account_info(),
+            authority,
         },
     );
 
-    token::transfer(transfer_ctx, amount)?;
+    token_interface::transfer_checked(transfer_ctx, amount, mint.decimals)?;
+
+    vault_token_account.reload()?;
+    Ok(vault_token_account.amount - balance_before)
+}
+
+pub fn check_game_access(ctx: Context<CheckGameAccess>, mode: GameMode) -> Result<()> {
+    let game_state = &ctx.accounts.game_state;
+    let player_pass = &mut ctx.accounts.player_pass;
+    let match_ticket = &mut ctx.accounts.match_ticket;
+    let clock = Clock::get()?;
+
+    // Players the program has never seen start on the free tier
+    if player_pass.player == Pubkey::default() {
+        player_pass.player = ctx.accounts.player.key();
+        player_pass.bump = ctx.bumps.player_pass;
+        player_pass.total_passes_purchased = 0;
+        player_pass.total_tokens_burned = 0;
+        player_pass.rent_payer = ctx.accounts.fee_payer.key();
+    }
+
+    require!(
+        !is_pass_suspended(player_pass, clock.unix_timestamp),
+        GameError::PassSuspended
+    );
+
+    // Players without a running pass may still play casual games from their free quota
+    let has_pass = player_pass.is_active && clock.unix_timestamp <= player_pass.pass_end_time;
+    let free_game = !has_pass && mode == GameMode::Casual;
+
+    if free_game {
+        let remaining = use_free_game(player_pass, game_state)?;
+        msg!("Free game access granted for player: {} ({} remaining)", ctx.accounts.player.key(), remaining);
+    } else {
+        // Check if pass is active
+        require!(player_pass.is_active, GameError::PassNotActive);
+        require!(clock.unix_timestamp <= player_pass.pass_end_time, GameError::PassExpired);
+
+        // Check the pass tier covers the requested mode
+        let pass_tier = ctx.accounts.pass_tier.as_ref().ok_or(GameError::PassTierRequired)?;
+        require!(tier_allows_mode(pass_tier, mode), GameError::GameModeNotAllowed);
+
+        msg!("Game access granted for player: {}", ctx.accounts.player.key());
+    }
 
-    msg!("Player burned {} WZN tokens for monthly pass", amount);
-    msg!("Pass valid until: {}", player_pass.pass_end_time);
+    // Issue a single-use ticket the match report must consume
+    match_ticket.bump = ctx.bumps.match_ticket;
+    match_ticket.player = ctx.accounts.player.key();
+    match_ticket.mode = mode;
+    match_ticket.nonce = player_pass.ticket_nonce;
+    match_ticket.issued_at = clock.unix_timestamp;
+    match_ticket.expires_at = clock.unix_timestamp + MATCH_TICKET_VALIDITY;
+    match_ticket.free_game = free_game;
+    match_ticket.payer = ctx.accounts.fee_payer.key();
+
+    player_pass.ticket_nonce += 1;
+    player_pass.last_activity = clock.unix_timestamp;
+
+    msg!("Match ticket {} valid until: {}", match_ticket.nonce, match_ticket.expires_at);
     Ok(())
 }
 
-pub fn check_game_access(ctx: Context<CheckGameAccess>) -> Result<()> {
-    let player_pass = &ctx.accounts.player_pass;
+pub fn close_expired_ticket(ctx: Context<CloseExpiredTicket>) -> Result<()> {
+    let match_ticket = &ctx.accounts.match_ticket;
     let clock = Clock::get()?;
 
-    // Check if pass is active
-    require!(player_pass.is_active, GameError::PassNotActive);
-    require!(clock.unix_timestamp <= player_pass.pass_end_time, GameError::PassExpired);
+    // A proposal made just before expiry holds its tickets through the attestation window, so they stay until that has passed too
+    require!(
+        clock.unix_timestamp > match_ticket.expires_at + MATCH_ATTESTATION_WINDOW,
+        GameError::MatchTicketStillValid
+    );
 
-    msg!("Game access granted for player: {}", ctx.accounts.player.key());
+    msg!("Closed expired match ticket {} for player: {}", match_ticket.nonce, match_ticket.player);
+    Ok(())
+}
+
+// Takes one game from the free quota. Returns the games left this period.
+fn use_free_game(player_pass: &mut PlayerPass, game_state: &GameState) -> Result<u8> {
+    let remaining = free_games_remaining(player_pass, game_state);
+    require!(remaining > 0, GameError::FreeQuotaExhausted);
+
+    if player_pass.free_games_period < game_state.last_monthly_reset {
+        player_pass.free_games_period = game_state.last_monthly_reset;
+        player_pass.free_games_used = 0;
+    }
+    player_pass.free_games_used += 1;
+
+    Ok(remaining - 1)
+}
+
+// Moderation Instructions
+pub fn initialize_role_registry(ctx: Context<InitializeRoleRegistry>, moderators: Vec<Pubkey>) -> Result<()> {
+    let role_registry = &mut ctx.accounts.role_registry;
+
+    require!(moderators.len() <= MAX_MODERATORS, GameError::TooManyRoleMembers);
+
+    role_registry.bump = ctx.bumps.role_registry;
+    role_registry.moderators = moderators;
+    role_registry.is_initialized = true;
+    role_registry.game_servers = Vec::new();
+    role_registry.arbiters = Vec::new();
+
+    msg!("Role registry initialized with {} moderators", role_registry.moderators.len());
+    Ok(())
+}
+
+pub fn update_moderators(ctx: Context<UpdateRoleRegistry>, moderators: Vec<Pubkey>) -> Result<()> {
+    let role_registry = &mut ctx.accounts.role_registry;
+
+    require!(moderators.len() <= MAX_MODERATORS, GameError::TooManyRoleMembers);
+
+    role_registry.moderators = moderators;
+
+    msg!("Moderators updated: {}", role_registry.moderators.len());
+    Ok(())
+}
+
+pub fn update_arbiters(ctx: Context<UpdateRoleRegistry>, arbiters: Vec<Pubkey>) -> Result<()> {
+    let role_registry = &mut ctx.accounts.role_registry;
+
+    require!(arbiters.len() <= MAX_ARBITERS, GameError::TooManyRoleMembers);
+
+    role_registry.arbiters = arbiters;
+
+    msg!("Arbiters updated: {}", role_registry.arbiters.len());
+    Ok(())
+}
+
+pub fn update_game_servers(ctx: Context<UpdateRoleRegistry>, game_servers: Vec<Pubkey>) -> Result<()> {
+    let role_registry = &mut ctx.accounts.role_registry;
+
+    require!(game_servers.len() <= MAX_GAME_SERVERS, GameError::TooManyRoleMembers);
+
+    role_registry.game_servers = game_servers;
+
+    msg!("Game servers updated: {}", role_registry.game_servers.len());
+    Ok(())
+}
+
+pub fn suspend_pass(ctx: Context<ModeratePass>, player: Pubkey, reason_code: u16, suspension_end: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    apply_pass_suspension(&mut ctx.accounts.player_pass, reason_code, suspension_end, now)?;
+
+    sync_optional_pass_token(
+        &ctx.accounts.player_pass,
+        &ctx.accounts.pass_mint,
+        &ctx.accounts.pass_token_account,
+        &ctx.accounts.pass_authority,
+        &ctx.accounts.pass_token_program,
+        now,
+    )?;
+
+    msg!("Pass suspended for player: {} (reason {}) until {}", player, reason_code, suspension_end);
+    Ok(())
+}
+
+pub fn reinstate_pass(ctx: Context<ModeratePass>, player: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    apply_pass_reinstatement(&mut ctx.accounts.player_pass, now)?;
+
+    sync_optional_pass_token(
+        &ctx.accounts.player_pass,
+        &ctx.accounts.pass_mint,
+        &ctx.accounts.pass_token_account,
+        &ctx.accounts.pass_authority,
+        &ctx.accounts.pass_token_program,
+        now,
+    )?;
+
+    msg!("Pass reinstated for player: {}", player);
+    Ok(())
+}
+
+// Shared by moderators and governance
+fn apply_pass_suspension(player_pass: &mut PlayerPass, reason_code: u16, suspension_end: i64, now: i64) -> Result<()> {
+    require!(!is_pass_suspended(player_pass, now), GameError::PassSuspended);
+    require!(
+        suspension_end > now && suspension_end - now <= MAX_SUSPENSION_DURATION,
+        GameError::InvalidSuspensionEnd
+    );
+
+    // Pause the pass clock by crediting the suspension up front
+    player_pass.suspension_credit = 0;
+    if player_pass.is_active && player_pass.pass_end_time > now {
+        player_pass.suspension_credit = suspension_end - now;
+        player_pass.pass_end_time += player_pass.suspension_credit;
+    }
+
+    player_pass.is_suspended = true;
+    player_pass.suspension_reason = reason_code;
+    player_pass.suspended_at = now;
+    player_pass.suspension_end = suspension_end;
+    Ok(())
+}
+
+fn apply_pass_reinstatement(player_pass: &mut PlayerPass, now: i64) -> Result<()> {
+    require!(is_pass_suspended(player_pass, now), GameError::PassNotSuspended);
+
+    // Take back the part of the up-front credit that was never served
+    let unserved = (player_pass.suspension_end - now).min(player_pass.suspension_credit);
+    player_pass.pass_end_time -= unserved;
+
+    player_pass.is_suspended = false;
+    player_pass.suspension_end = now;
+    player_pass.suspension_credit -= unserved;
+    Ok(())
+}
+
+// Pass Token Instructions
+pub fn create_pass_token(ctx: Context<CreatePassToken>) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    let mint_key = ctx.accounts.pass_mint.key();
+    let token_program_id = ctx.accounts.pass_token_program.key();
+    let pass_mint_info = ctx.accounts.pass_mint.to_account_info();
+    let pass_authority_info = ctx.accounts.pass_authority.to_account_info();
+
+    let mint_bump = ctx.bumps.pass_mint;
+    let mint_seeds: &[&[u8]] = &[PASS_MINT_SEED, player_key.as_ref(), std::slice::from_ref(&mint_bump)];
+    let authority_bump = ctx.bumps.pass_authority;
+    let authority_seeds = pass_authority_signer_seeds(&authority_bump);
+
+    // A player who closed and reopened their pass keeps the mint created the first time
+    if pass_mint_info.data_is_empty() {
+        create_pass_mint(&ctx, &pass_mint_info, &pass_authority_info, mint_seeds, &authority_seeds)?;
+    } else {
+        require_keys_eq!(*pass_mint_info.owner, token_program_id, GameError::InvalidPassToken);
+    }
+    update_pass_token_metadata(&ctx.accounts.player_pass, &pass_mint_info, &pass_authority_info, &token_program_id, &authority_seeds)?;
+
+    associated_token::create_idempotent(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.fee_payer.to_account_info(),
+            associated_token: ctx.accounts.pass_token_account.to_account_info(),
+            authority: ctx.accounts.player.to_account_info(),
+            mint: pass_mint_info.clone(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.pass_token_program.to_account_info(),
+        },
+    ))?;
+    let held = {
+        let data = ctx.accounts.pass_token_account.try_borrow_data()?;
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?.base.amount
+    };
+
+    // Mirror a pass that is already running
+    let now = Clock::get()?.unix_timestamp;
+    let player_pass = &mut ctx.accounts.player_pass;
+    if held == 0 && player_pass.is_active && now <= player_pass.pass_end_time && !is_pass_suspended(player_pass, now) {
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.pass_token_program.to_account_info(),
+                token_2022::MintTo {
+                    mint: pass_mint_info,
+                    to: ctx.accounts.pass_token_account.to_account_info(),
+                    authority: pass_authority_info,
+                },
+                &[&authority_seeds],
+            ),
+            1,
+        )?;
+    }
+    player_pass.pass_token_mint = Some(mint_key);
+
+    msg!("Pass token {} created for player: {}", mint_key, player_key);
+    Ok(())
+}
+
+fn create_pass_mint<'info>(
+    ctx: &Context<CreatePassToken<'info>>,
+    pass_mint_info: &AccountInfo<'info>,
+    pass_authority_info: &AccountInfo<'info>,
+    mint_seeds: &[&[u8]],
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let mint_key = pass_mint_info.key();
+    let authority_key = pass_authority_info.key();
+    let token_program_id = ctx.accounts.pass_token_program.key();
+
+    // Allocate the mint for its fixed extensions and fund the metadata written after it
+    let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::MetadataPointer,
+    ])?;
+    let lamports = Rent::get()?.minimum_balance(mint_len + pass_token_metadata_len());
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.fee_payer.to_account_info(),
+                to: pass_mint_info.clone(),
+            },
+            &[mint_seeds],
+        ),
+        lamports,
+        mint_len as u64,
+        &token_program_id,
+    )?;
+
+    // Soulbound, burnable by the program at expiry, and self-describing
+    invoke(
+        &spl_token_2022::instruction::initialize_non_transferable_mint(&token_program_id, &mint_key)?,
+        std::slice::from_ref(pass_mint_info),
+    )?;
+    invoke(
+        &spl_token_2022::instruction::initialize_permanent_delegate(&token_program_id, &mint_key, &authority_key)?,
+        std::slice::from_ref(pass_mint_info),
+    )?;
+    invoke(
+        &metadata_pointer::instruction::initialize(&token_program_id, &mint_key, Some(authority_key), Some(mint_key))?,
+        std::slice::from_ref(pass_mint_info),
+    )?;
+    invoke(
+        &spl_token_2022::instruction::initialize_mint2(&token_program_id, &mint_key, &authority_key, Some(&authority_key), 0)?,
+        std::slice::from_ref(pass_mint_info),
+    )?;
+
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::initialize(
+            &token_program_id,
+            &mint_key,
+            &authority_key,
+            &mint_key,
+            &authority_key,
+            PASS_TOKEN_NAME.to_string(),
+            PASS_TOKEN_SYMBOL.to_string(),
+            PASS_TOKEN_URI.to_string(),
+        ),
+        &[pass_mint_info.clone(), pass_authority_info.clone()],
+        &[authority_seeds],
+    )?;
+    Ok(())
+}
+
+pub fn sync_pass_token(ctx: Context<SyncPassToken>, player: Pubkey) -> Result<()> {
+    apply_pass_token_sync(
+        &ctx.accounts.player_pass,
+        &ctx.accounts.pass_mint,
+        &ctx.accounts.pass_token_account,
+        &ctx.accounts.pass_authority,
+        &ctx.accounts.pass_token_program,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    msg!("Pass token synced for player: {}", player);
+    Ok(())
+}
+
+// Keeps the pass token in step when the pass has one; its accounts must then be supplied
+fn sync_optional_pass_token<'info>(
+    player_pass: &PlayerPass,
+    pass_mint: &Option<InterfaceAccount<'info, Mint>>,
+    pass_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+    pass_authority: &Option<UncheckedAccount<'info>>,
+    pass_token_program: &Option<Program<'info, token_2022::Token2022>>,
+    now: i64,
+) -> Result<()> {
+    if player_pass.pass_token_mint.is_none() {
+        return Ok(());
+    }
+    match (pass_mint, pass_token_account, pass_authority, pass_token_program) {
+        (Some(pass_mint), Some(pass_token_account), Some(pass_authority), Some(pass_token_program)) => {
+            apply_pass_token_sync(player_pass, pass_mint, pass_token_account, pass_authority, pass_token_program, now)
+        }
+        _ => err!(GameError::PassTokenAccountsRequired),
+    }
+}
+
+// Mirrors the pass onto its token: frozen while suspended, held with current metadata while running, burned once expired
+fn apply_pass_token_sync<'info>(
+    player_pass: &PlayerPass,
+    pass_mint: &InterfaceAccount<'info, Mint>,
+    pass_token_account: &InterfaceAccount<'info, TokenAccount>,
+    pass_authority: &UncheckedAccount<'info>,
+    pass_token_program: &Program<'info, token_2022::Token2022>,
+    now: i64,
+) -> Result<()> {
+    let (authority_key, authority_bump) = get_pass_authority_pda();
+    require!(player_pass.pass_token_mint == Some(pass_mint.key()), GameError::InvalidPassToken);
+    require_keys_eq!(pass_token_account.mint, pass_mint.key(), GameError::InvalidPassToken);
+    require_keys_eq!(pass_token_account.owner, player_pass.player, GameError::InvalidPassToken);
+    require_keys_eq!(pass_authority.key(), authority_key, GameError::InvalidPassToken);
+
+    let authority_seeds = pass_authority_signer_seeds(&authority_bump);
+    let signer = &[&authority_seeds[..]];
+    let token_program = pass_token_program.to_account_info();
+    let mint_info = pass_mint.to_account_info();
+    let account_info = pass_token_account.to_account_info();
+    let authority_info = pass_authority.to_account_info();
+
+    if is_pass_suspended(player_pass, now) {
+        if !pass_token_account.is_frozen() {
+            token_2022::freeze_account(CpiContext::new_with_signer(
+                token_program,
+                token_2022::FreezeAccount {
+                    account: account_info,
+                    mint: mint_info,
+                    authority: authority_info,
+                },
+                signer,
+            ))?;
+        }
+        return Ok(());
+    }
+
+    if pass_token_account.is_frozen() {
+        token_2022::thaw_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            token_2022::ThawAccount {
+                account: account_info.clone(),
+                mint: mint_info.clone(),
+                authority: authority_info.clone(),
+            },
+            signer,
+        ))?;
+    }
+
+    if player_pass.is_active && now <= player_pass.pass_end_time {
+        update_pass_token_metadata(player_pass, &mint_info, &authority_info, &pass_token_program.key(), &authority_seeds)?;
+        if pass_token_account.amount == 0 {
+            token_2022::mint_to(
+                CpiContext::new_with_signer(
+                    token_program,
+                    token_2022::MintTo {
+                        mint: mint_info,
+                        to: account_info,
+                        authority: authority_info,
+                    },
+                    signer,
+                ),
+                1,
+            )?;
+        }
+    } else if pass_token_account.amount > 0 {
+        // The pass authority is the mint's permanent delegate
+        token_2022::burn(
+            CpiContext::new_with_signer(
+                token_program,
+                token_2022::Burn {
+                    mint: mint_info,
+                    from: account_info,
+                    authority: authority_info,
+                },
+                signer,
+            ),
+            pass_token_account.amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn update_pass_token_metadata<'info>(
+    player_pass: &PlayerPass,
+    pass_mint: &AccountInfo<'info>,
+    pass_authority: &AccountInfo<'info>,
+    token_program_id: &Pubkey,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let fields = [
+        (PASS_TIER_FIELD, pass_tier_field_value(player_pass.tier_id)),
+        (PASS_EXPIRY_FIELD, pass_expiry_field_value(player_pass.pass_end_time)),
+    ];
+    for (key, value) in fields {
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::update_field(
+                token_program_id,
+                pass_mint.key,
+                pass_authority.key,
+                Field::Key(key.to_string()),
+                value,
+            ),
+            &[pass_mint.clone(), pass_authority.clone()],
+            &[authority_seeds],
+        )?;
+    }
     Ok(())
 }
 
@@ -103,8 +958,11 @@ pub fn initialize_burn_vault(ctx: Context<InitializeBurnVault>, emergency_thresh
     burn_vault.minimum_balance_threshold = minimum_balance;
     burn_vault.is_initialized = true;
     burn_vault.unlock_delay = EMERGENCY_UNLOCK_DELAY;
+    burn_vault.token_account = ctx.accounts.burn_vault_token_account.key();
+    burn_vault.token_account_bump = ctx.bumps.burn_vault_token_account;
 
     msg!("Burn vault initialized");
+    msg!("Burn vault token account: {}", burn_vault.token_account);
     msg!("Emergency threshold: {}", emergency_threshold);
     msg!("Minimum balance: {}", minimum_balance);
     Ok(())
@@ -118,30 +976,44 @@ pub fn initialize_prize_vault(ctx: Context<InitializePrizeVault>) -> Result<()>
     prize_vault.total_distributed = 0;
     prize_vault.last_distribution = 0;
     prize_vault.is_initialized = true;
+    prize_vault.token_account = ctx.accounts.prize_vault_token_account.key();
+    prize_vault.token_account_bump = ctx.bumps.prize_vault_token_account;
 
     msg!("Prize vault initialized");
+    msg!("Prize vault token account: {}", prize_vault.token_account);
     Ok(())
 }
 
-pub fn deposit_to_prize_vault(ctx: Context<DepositToPrizeVault>, amount: u64) -> Result<()> {
-    let prize_vault = &mut ctx.accounts.prize_vault;
+pub fn initialize_recovery_vault(ctx: Context<InitializeRecoveryVault>) -> Result<()> {
+    let recovery_vault = &mut ctx.accounts.recovery_vault;
 
-    // Transfer tokens to prize vault
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.from_token_account.to_account_info(),
-            to: ctx.accounts.prize_vault_token_account.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
-        },
-    );
+    recovery_vault.bump = ctx.bumps.recovery_vault;
+    recovery_vault.total_received = 0;
+    recovery_vault.is_initialized = true;
+    recovery_vault.token_account = ctx.accounts.recovery_vault_token_account.key();
+    recovery_vault.token_account_bump = ctx.bumps.recovery_vault_token_account;
+    recovery_vault.total_withdrawn = 0;
+
+    msg!("Recovery vault initialized");
+    msg!("Recovery vault token account: {}", recovery_vault.token_account);
+    Ok(())
+}
 
-    token::transfer(transfer_ctx, amount)?;
+pub fn deposit_to_prize_vault(ctx: Context<DepositToPrizeVault>, amount: u64) -> Result<()> {
+    // Transfer tokens to prize vault
+    let received = deposit_into_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.from_token_account,
+        &mut ctx.accounts.prize_vault_token_account,
+        &ctx.accounts.wzn_mint,
+        ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
 
     // Update prize vault state
-    prize_vault.total_deposited += amount;
+    ctx.accounts.prize_vault.total_deposited += received;
 
-    msg!("Deposited {} WZN to prize vault", amount);
+    msg!("Deposited {} WZN to prize vault ({} received)", amount, received);
     Ok(())
 }
 
@@ -229,10 +1101,12 @@ pub fn vote_on_proposal(ctx: Context<VoteOnProposal>, proposal_id: u64, vote_for
 }
 
 pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
+    let burn_vault_info = ctx.accounts.burn_vault.to_account_info();
     let dao_governance = &mut ctx.accounts.dao_governance;
     let burn_vault = &mut ctx.accounts.burn_vault;
     let prize_vault = &mut ctx.accounts.prize_vault;
     let game_state = &mut ctx.accounts.game_state;
+    let fee_config = &mut ctx.accounts.fee_config;
     let clock = Clock::get()?;
 
     // Check if executor is a DAO member
@@ -241,6 +1115,8 @@ pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Resu
         GameError::DAOMemberNotFound
     );
 
+    let required_votes = (dao_governance.total_members * dao_governance.quorum_percentage as u32) / 100;
+
     // Find proposal
     let proposal = dao_governance
         .pending_proposals
@@ -251,28 +1127,188 @@ pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Resu
     require!(!proposal.is_executed, GameError::ProposalAlreadyExecuted);
 
     // Check quorum
-    let required_votes = (dao_governance.total_members * dao_governance.quorum_percentage as u32) / 100;
     require!(proposal.total_votes >= required_votes, GameError::InsufficientVotes);
 
     // Execute proposal based on type
     match proposal.proposal_type {
         ProposalType::UnlockBurnVault => {
             require!(proposal.amount <= burn_vault.total_locked, GameError::InvalidAmount);
+            require!(
+                proposal.amount <= ctx.accounts.burn_vault_token_account.amount,
+                GameError::InsufficientTokens
+            );
+
+            // Unlocked tokens move into the prize vault, signed by the burn vault PDA
+            let bump = burn_vault.bump;
+            let seeds = burn_vault_signer_seeds(&bump);
+            let signer = &[&seeds[..]];
+            let balance_before = ctx.accounts.prize_vault_token_account.amount;
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.burn_vault_token_account.to_account_info(),
+                    mint: ctx.accounts.wzn_mint.to_account_info(),
+                    to: ctx.accounts.prize_vault_token_account.to_account_info(),
+                    authority: burn_vault_info,
+                },
+                signer,
+            );
+
+            token_interface::transfer_checked(transfer_ctx, proposal.amount, ctx.accounts.wzn_mint.decimals)?;
+            ctx.accounts.prize_vault_token_account.reload()?;
+            let received = ctx.accounts.prize_vault_token_account.amount - balance_before;
+
             burn_vault.total_locked -= proposal.amount;
             burn_vault.total_unlocked += proposal.amount;
             burn_vault.last_dao_unlock = clock.unix_timestamp;
-            msg!("Unlocked {} WZN from burn vault", proposal.amount);
+            prize_vault.total_deposited += received;
+            msg!("Unlocked {} WZN from burn vault into prize vault", proposal.amount);
         }
-        ProposalType::DistributePrizes => {
-            require!(proposal.amount <= prize_vault.total_deposited, GameError::InvalidAmount);
+        ProposalType::DistributePrizes { recipient } => {
+            let player_score = ctx.accounts.player_score.as_mut().ok_or(GameError::PlayerScoreRequired)?;
+            require_keys_eq!(player_score.player, recipient, GameError::PlayerScoreRequired);
+            let available = prize_vault
+                .total_deposited
+                .checked_sub(prize_vault.total_distributed)
+                .ok_or(GameError::MathOverflow)?;
+            require!(proposal.amount <= available, GameError::InsufficientPrizeFunds);
+
+            // Earmarked for the recipient, who claims it with distribute_prize
             prize_vault.total_distributed += proposal.amount;
+            player_score.unclaimed_prizes += proposal.amount;
             game_state.total_prizes_distributed += proposal.amount;
-            msg!("Distributed {} WZN in prizes", proposal.amount);
+            msg!("Awarded {} WZN in prizes to {}", proposal.amount, recipient);
         }
         ProposalType::UpdateMonthlyPassCost => {
+            fold_elapsed_price_steps(game_state, clock.unix_timestamp);
             game_state.monthly_pass_cost = proposal.amount;
             msg!("Updated monthly pass cost to {}", proposal.amount);
         }
+        ProposalType::UpdateAttestationThreshold => {
+            require!(is_valid_attestation_threshold(proposal.amount), GameError::InvalidAttestationThreshold);
+            game_state.attestation_threshold = proposal.amount as u8;
+            msg!("Updated attestation threshold to {} of {} seats", proposal.amount, MATCH_PLAYERS);
+        }
+        ProposalType::UpdateDisputeWindow => {
+            require!(
+                proposal.amount > 0 && proposal.amount <= MAX_DISPUTE_WINDOW as u64,
+                GameError::InvalidDisputeWindow
+            );
+            game_state.dispute_window = proposal.amount as i64;
+            msg!("Updated dispute window to {} seconds", proposal.amount);
+        }
+        ProposalType::UpdateProvisionalGames => {
+            require!(proposal.amount <= MAX_PROVISIONAL_GAMES as u64, GameError::InvalidAmount);
+            game_state.provisional_games = proposal.amount as u32;
+            msg!("Updated provisional period to {} games", proposal.amount);
+        }
+        ProposalType::UpdateRatingDecayWeeks => {
+            require!(proposal.amount > 0 && proposal.amount <= u32::MAX as u64, GameError::InvalidAmount);
+            game_state.rating_decay_weeks = proposal.amount as u32;
+            msg!("Updated rating decay to start after {} idle weeks", proposal.amount);
+        }
+        ProposalType::UpdateRatingFloor => {
+            require!(proposal.amount <= u32::MAX as u64, GameError::InvalidAmount);
+            game_state.rating_floor = proposal.amount as u32;
+            msg!("Updated rating floor to {}", proposal.amount);
+        }
+        ProposalType::UpdateInactivityCloseMonths => {
+            require!(proposal.amount > 0 && proposal.amount <= u8::MAX as u64, GameError::InvalidAmount);
+            game_state.inactivity_close_months = proposal.amount as u8;
+            msg!("Updated inactivity close period to {} months", proposal.amount);
+        }
+        ProposalType::SchedulePassCost { effective_at } => {
+            require!(effective_at > clock.unix_timestamp, GameError::InvalidPriceStep);
+            fold_elapsed_price_steps(game_state, clock.unix_timestamp);
+
+            // A step at the same time is amended in place; a zero cost cancels it
+            game_state.price_schedule.retain(|step| step.effective_at != effective_at);
+            if proposal.amount > 0 {
+                require!(game_state.price_schedule.len() < MAX_PRICE_STEPS, GameError::TooManyPriceSteps);
+                let index = game_state.price_schedule.partition_point(|step| step.effective_at < effective_at);
+                game_state.price_schedule.insert(index, PriceStep { effective_at, cost: proposal.amount });
+            }
+            msg!("Scheduled monthly pass cost {} from {}", proposal.amount, effective_at);
+        }
+        ProposalType::UpdateMaxPrepaidMonths => {
+            require!(
+                proposal.amount > 0 && proposal.amount <= MAX_PREPAID_MONTHS_LIMIT as u64,
+                GameError::InvalidPassMonths
+            );
+            game_state.max_prepaid_months = proposal.amount as u8;
+            msg!("Updated maximum prepaid months to {}", proposal.amount);
+        }
+        ProposalType::UpdateFeeMode => {
+            let fee_config = fee_config.as_mut().ok_or(GameError::FeeConfigNotInitialized)?;
+            fee_config.fee_mode = fee_mode_from_u64(proposal.amount).ok_or(GameError::InvalidFeeMode)?;
+            msg!("Updated fee mode to {}", proposal.amount);
+        }
+        ProposalType::AddFeeSponsor { sponsor } => {
+            let fee_config = fee_config.as_mut().ok_or(GameError::FeeConfigNotInitialized)?;
+            if !fee_config.approved_sponsors.contains(&sponsor) {
+                require!(fee_config.approved_sponsors.len() < MAX_FEE_SPONSORS, GameError::TooManySponsors);
+                fee_config.approved_sponsors.push(sponsor);
+            }
+            msg!("Approved sponsor fee payer: {}", sponsor);
+        }
+        ProposalType::RemoveFeeSponsor { sponsor } => {
+            let fee_config = fee_config.as_mut().ok_or(GameError::FeeConfigNotInitialized)?;
+            fee_config.approved_sponsors.retain(|approved| *approved != sponsor);
+            msg!("Removed sponsor fee payer: {}", sponsor);
+        }
+        ProposalType::UpdateFreeGamesPerPeriod => {
+            require!(proposal.amount <= u8::MAX as u64, GameError::InvalidAmount);
+            game_state.free_games_per_period = proposal.amount as u8;
+            msg!("Updated free games per period to {}", proposal.amount);
+        }
+        ProposalType::UpdateBurnBps => {
+            require!(proposal.amount <= BPS_DENOMINATOR, GameError::InvalidBurnBps);
+            game_state.burn_bps = proposal.amount as u16;
+            msg!("Updated burn share to {} bps", proposal.amount);
+        }
+        ProposalType::UpdateBurnRoutes { routes } => {
+            game_state.burn_routes = validated_burn_routes(&routes).ok_or(GameError::InvalidBurnRoutes)?;
+            msg!("Updated burn routing table: {} leg(s)", game_state.burn_routes.len());
+        }
+        ProposalType::WithdrawRecoveryVault { recipient } => {
+            let recovery_vault = ctx.accounts.recovery_vault.as_mut().ok_or(GameError::RecoveryVaultNotInitialized)?;
+            let vault_token_account = ctx.accounts.recovery_vault_token_account.as_ref().ok_or(GameError::InvalidTokenAccount)?;
+            let recipient_token_account = ctx.accounts.recipient_token_account.as_ref().ok_or(GameError::InvalidTokenAccount)?;
+            require_keys_eq!(vault_token_account.key(), recovery_vault.token_account, GameError::InvalidTokenAccount);
+            require_keys_eq!(recipient_token_account.owner, recipient, GameError::InvalidTokenAccount);
+            require!(proposal.amount <= vault_token_account.amount, GameError::InsufficientTokens);
+
+            // Paid out of the recovery vault, signed by its PDA
+            let bump = recovery_vault.bump;
+            let seeds = recovery_vault_signer_seeds(&bump);
+            let signer = &[&seeds[..]];
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: vault_token_account.to_account_info(),
+                    mint: ctx.accounts.wzn_mint.to_account_info(),
+                    to: recipient_token_account.to_account_info(),
+                    authority: recovery_vault.to_account_info(),
+                },
+                signer,
+            );
+
+            token_interface::transfer_checked(transfer_ctx, proposal.amount, ctx.accounts.wzn_mint.decimals)?;
+            recovery_vault.total_withdrawn += proposal.amount;
+            msg!("Withdrew {} WZN from recovery vault to {}", proposal.amount, recipient);
+        }
+        ProposalType::SuspendPlayer { player, reason_code, suspension_end } => {
+            let player_pass = ctx.accounts.player_pass.as_mut().ok_or(GameError::PlayerPassRequired)?;
+            require_keys_eq!(player_pass.player, player, GameError::PlayerPassRequired);
+            apply_pass_suspension(player_pass, reason_code, suspension_end, clock.unix_timestamp)?;
+            msg!("Pass suspended for player: {} (reason {}) until {}", player, reason_code, suspension_end);
+        }
+        ProposalType::ReinstatePlayer { player } => {
+            let player_pass = ctx.accounts.player_pass.as_mut().ok_or(GameError::PlayerPassRequired)?;
+            require_keys_eq!(player_pass.player, player, GameError::PlayerPassRequired);
+            apply_pass_reinstatement(player_pass, clock.unix_timestamp)?;
+            msg!("Pass reinstated for player: {}", player);
+        }
         _ => {
             return err!(GameError::InvalidProposalType);
         }
@@ -299,12 +1335,58 @@ pub fn initialize_emergency_recovery(ctx: Context<InitializeEmergencyRecovery>,
     emergency_recovery.is_initialized = true;
     emergency_recovery.emergency_active = false;
     emergency_recovery.emergency_start_time = 0;
+    emergency_recovery.pending_unlock_amount = 0;
+    emergency_recovery.pending_unlock_recipient = Pubkey::default();
+    emergency_recovery.pending_unlock_requested_at = 0;
+    emergency_recovery.unlock_approvals = Vec::new();
 
     msg!("Emergency recovery initialized with {} members", emergency_recovery.total_members);
     Ok(())
 }
 
+// Backup members approve one unlock (amount and recipient) at a time, like votes on a DAO proposal
+pub fn approve_emergency_unlock(ctx: Context<ApproveEmergencyUnlock>, amount: u64, recipient: Pubkey) -> Result<()> {
+    let emergency_recovery = &mut ctx.accounts.emergency_recovery;
+    let member = ctx.accounts.member.key();
+    let clock = Clock::get()?;
+
+    require!(
+        emergency_recovery.backup_members.contains(&member),
+        GameError::EmergencyMemberNotFound
+    );
+    require!(amount > 0, GameError::InvalidAmount);
+
+    if is_emergency_unlock_pending(emergency_recovery, clock.unix_timestamp) {
+        // Members cannot replace a live request, only add their approval to it
+        require!(
+            emergency_recovery.pending_unlock_amount == amount && emergency_recovery.pending_unlock_recipient == recipient,
+            GameError::EmergencyUnlockPending
+        );
+    } else {
+        emergency_recovery.pending_unlock_amount = amount;
+        emergency_recovery.pending_unlock_recipient = recipient;
+        emergency_recovery.pending_unlock_requested_at = clock.unix_timestamp;
+        emergency_recovery.unlock_approvals.clear();
+    }
+
+    if !emergency_recovery.unlock_approvals.contains(&member) {
+        emergency_recovery.unlock_approvals.push(member);
+    }
+    emergency_recovery.last_activity = clock.unix_timestamp;
+
+    msg!(
+        "Emergency unlock of {} WZN to {} approved by {} ({}/{})",
+        amount,
+        recipient,
+        member,
+        emergency_recovery.unlock_approvals.len(),
+        emergency_approvals_required(emergency_recovery)
+    );
+    Ok(())
+}
+
 pub fn emergency_unlock(ctx: Context<EmergencyUnlock>, amount: u64, percentage: u64) -> Result<()> {
+    let burn_vault_info = ctx.accounts.burn_vault.to_account_info();
     let emergency_recovery = &mut ctx.accounts.emergency_recovery;
     let burn_vault = &mut ctx.accounts.burn_vault;
     let clock = Clock::get()?;
@@ -315,6 +1397,15 @@ pub fn emergency_unlock(ctx: Context<EmergencyUnlock>, amount: u64, percentage:
         GameError::EmergencyMemberNotFound
     );
 
+    // The exact unlock must have reached the backup members' quorum
+    require!(
+        is_emergency_unlock_pending(emergency_recovery, clock.unix_timestamp)
+            && emergency_recovery.pending_unlock_amount == amount
+            && emergency_recovery.pending_unlock_recipient == ctx.accounts.recipient.key()
+            && emergency_recovery.unlock_approvals.len() >= emergency_approvals_required(emergency_recovery),
+        GameError::EmergencyUnlockNotApproved
+    );
+
     // Check emergency conditions
     require!(can_emergency_unlock(burn_vault), GameError::EmergencyConditionsNotMet);
     require!(percentage <= MAX_EMERGENCY_UNLOCK_PERCENTAGE, GameError::InvalidUnlockPercentage);
@@ -322,102 +1413,876 @@ pub fn emergency_unlock(ctx: Context<EmergencyUnlock>, amount: u64, percentage:
     // Calculate unlock amount
     let calculated_amount = calculate_emergency_unlock_amount(burn_vault.total_locked, percentage);
     require!(amount <= calculated_amount, GameError::InvalidEmergencyUnlockAmount);
+    require!(
+        amount <= ctx.accounts.burn_vault_token_account.amount,
+        GameError::InsufficientTokens
+    );
 
     // Update emergency recovery state
     emergency_recovery.emergency_active = true;
     emergency_recovery.emergency_start_time = clock.unix_timestamp;
     emergency_recovery.last_activity = clock.unix_timestamp;
+    emergency_recovery.pending_unlock_amount = 0;
+    emergency_recovery.unlock_approvals.clear();
 
     // Update burn vault
     burn_vault.total_locked -= amount;
     burn_vault.total_unlocked += amount;
 
-    // Transfer tokens to recipient
-    let transfer_ctx = CpiContext::new(
+    // Transfer tokens to recipient, signed by the burn vault PDA
+    let bump = burn_vault.bump;
+    let seeds = burn_vault_signer_seeds(&bump);
+    let signer = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.burn_vault_token_account.to_account_info(),
+            mint: ctx.accounts.wzn_mint.to_account_info(),
             to: ctx.accounts.recipient_token_account.to_account_info(),
-            authority: ctx.accounts.recipient.to_account_info(),
+            authority: burn_vault_info,
         },
+        signer,
     );
 
-    token::transfer(transfer_ctx, amount)?;
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.wzn_mint.decimals)?;
 
     msg!("Emergency unlock executed: {} WZN ({}%)", amount, percentage);
     Ok(())
 }
 
 // Player Score Management Instructions
-pub fn update_player_score(
-    ctx: Context<UpdatePlayerScore>,
-    games_played: u32,
-    games_won: u32,
-    rating_change: i32,
+pub fn report_match_result(
+    ctx: Context<ReportMatchResult>,
+    game_id: u64,
+    players: [Pubkey; MATCH_PLAYERS],
+    result: MatchResult,
 ) -> Result<()> {
-    let player_score = &mut ctx.accounts.player_score;
     let clock = Clock::get()?;
+    let game_server = ctx.accounts.game_server.key();
+
+    require!(is_valid_match_result(&result), GameError::InvalidMatchResult);
+
+    let match_record = &mut ctx.accounts.match_record;
+    open_match_record(
+        match_record,
+        ctx.bumps.match_record,
+        game_id,
+        players,
+        &result,
+        [
+            &ctx.accounts.match_ticket_0,
+            &ctx.accounts.match_ticket_1,
+            &ctx.accounts.match_ticket_2,
+            &ctx.accounts.match_ticket_3,
+        ],
+        clock.unix_timestamp,
+    )?;
+    match_record.reporter = game_server;
+
+    let bumps = [
+        ctx.bumps.player_score_0,
+        ctx.bumps.player_score_1,
+        ctx.bumps.player_score_2,
+        ctx.bumps.player_score_3,
+    ];
+    apply_match_result(
+        &mut ctx.accounts.match_record,
+        [
+            &mut ctx.accounts.player_score_0,
+            &mut ctx.accounts.player_score_1,
+            &mut ctx.accounts.player_score_2,
+            &mut ctx.accounts.player_score_3,
+        ],
+        bumps,
+        game_server,
+        &mut ctx.accounts.leaderboard,
+        &ctx.accounts.game_state,
+        clock.unix_timestamp,
+    );
+
+    msg!("Match {} reported by game server: {}", game_id, game_server);
+    Ok(())
+}
+
+// Seated players propose the result themselves; it only counts once enough of the table attests
+pub fn propose_match_result(
+    ctx: Context<ProposeMatchResult>,
+    game_id: u64,
+    players: [Pubkey; MATCH_PLAYERS],
+    result: MatchResult,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let proposer = ctx.accounts.proposer.key();
+
+    require!(is_valid_match_result(&result), GameError::InvalidMatchResult);
+    let seat = players.iter().position(|player| *player == proposer).ok_or(GameError::NotSeatedPlayer)?;
+
+    let match_record = &mut ctx.accounts.match_record;
+    open_match_record(
+        match_record,
+        ctx.bumps.match_record,
+        game_id,
+        players,
+        &result,
+        [
+            &ctx.accounts.match_ticket_0,
+            &ctx.accounts.match_ticket_1,
+            &ctx.accounts.match_ticket_2,
+            &ctx.accounts.match_ticket_3,
+        ],
+        clock.unix_timestamp,
+    )?;
+    match_record.reporter = proposer;
+    match_record.attestation_deadline = clock.unix_timestamp + MATCH_ATTESTATION_WINDOW;
+
+    // The threshold is always above one seat, so the proposal alone never records the result
+    match_record.attestations = 1 << seat;
+
+    msg!("Match {} proposed by seat {}, awaiting attestation", game_id, seat);
+    Ok(())
+}
+
+pub fn attest_match_result(ctx: Context<AttestMatchResult>, game_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let attester = ctx.accounts.attester.key();
+    let match_record = &mut ctx.accounts.match_record;
 
-    // Initialize player score if needed
+    require!(
+        match_record.status == MatchStatus::AwaitingAttestation,
+        GameError::MatchNotAwaitingAttestation
+    );
+    let seat = match_record
+        .players
+        .iter()
+        .position(|player| *player == attester)
+        .ok_or(GameError::NotSeatedPlayer)?;
+    require!(
+        (match_record.attestations | match_record.rejections) & (1 << seat) == 0,
+        GameError::AlreadyAttested
+    );
+    require!(clock.unix_timestamp <= match_record.attestation_deadline, GameError::AttestationWindowClosed);
+
+    match_record.attestations |= 1 << seat;
+    let attestation_count = match_record.attestations.count_ones();
+
+    // Short of the threshold the match simply stays pending, so a missing signature never records a wrong result
+    if attestation_count < ctx.accounts.game_state.attestation_threshold as u32 {
+        msg!("Match {} attested by seat {} ({} signatures)", game_id, seat, attestation_count);
+        return Ok(());
+    }
+
+    let bumps = [
+        ctx.bumps.player_score_0,
+        ctx.bumps.player_score_1,
+        ctx.bumps.player_score_2,
+        ctx.bumps.player_score_3,
+    ];
+    apply_match_result(
+        &mut ctx.accounts.match_record,
+        [
+            &mut ctx.accounts.player_score_0,
+            &mut ctx.accounts.player_score_1,
+            &mut ctx.accounts.player_score_2,
+            &mut ctx.accounts.player_score_3,
+        ],
+        bumps,
+        attester,
+        &mut ctx.accounts.leaderboard,
+        &ctx.accounts.game_state,
+        clock.unix_timestamp,
+    );
+
+    // Only now are the tickets consumed; until then the proposal holds them
+    let accounts = &ctx.accounts;
+    accounts.match_ticket_0.close(accounts.ticket_payer_0.to_account_info())?;
+    accounts.match_ticket_1.close(accounts.ticket_payer_1.to_account_info())?;
+    accounts.match_ticket_2.close(accounts.ticket_payer_2.to_account_info())?;
+    accounts.match_ticket_3.close(accounts.ticket_payer_3.to_account_info())?;
+
+    msg!("Match {} recorded with {} signatures", game_id, attestation_count);
+    Ok(())
+}
+
+// A seat that disagrees with a proposed result rejects it; once the threshold is out of reach the
+// match is contested and left for an arbiter, so the losing seats cannot simply erase the result
+pub fn reject_match_result(ctx: Context<RejectMatchResult>, game_id: u64) -> Result<()> {
+    let rejecter = ctx.accounts.rejecter.key();
+    let match_record = &mut ctx.accounts.match_record;
+
+    require!(
+        match_record.status == MatchStatus::AwaitingAttestation,
+        GameError::MatchNotAwaitingAttestation
+    );
+    let seat = match_record
+        .players
+        .iter()
+        .position(|player| *player == rejecter)
+        .ok_or(GameError::NotSeatedPlayer)?;
+    require!(
+        (match_record.attestations | match_record.rejections) & (1 << seat) == 0,
+        GameError::AlreadyAttested
+    );
+
+    match_record.rejections |= 1 << seat;
+
+    if can_reach_attestation_threshold(match_record, ctx.accounts.game_state.attestation_threshold) {
+        msg!("Match {} rejected by seat {}", game_id, seat);
+        return Ok(());
+    }
+
+    match_record.status = MatchStatus::Contested;
+    match_record.disputed_by = Some(rejecter);
+    match_record.season = ctx.accounts.game_state.current_season;
+
+    // The game was played either way, so the tickets are spent and cannot back a second proposal
+    let accounts = &ctx.accounts;
+    accounts.match_ticket_0.close(accounts.ticket_payer_0.to_account_info())?;
+    accounts.match_ticket_1.close(accounts.ticket_payer_1.to_account_info())?;
+    accounts.match_ticket_2.close(accounts.ticket_payer_2.to_account_info())?;
+    accounts.match_ticket_3.close(accounts.ticket_payer_3.to_account_info())?;
+
+    msg!("Match {} contested after rejection by seat {}", game_id, seat);
+    Ok(())
+}
+
+// Anyone may clear a proposal the table never attested in time
+pub fn close_stale_match_proposal(ctx: Context<CloseStaleMatchProposal>, game_id: u64) -> Result<()> {
+    let match_record = &ctx.accounts.match_record;
+    let clock = Clock::get()?;
+
+    require!(
+        match_record.status == MatchStatus::AwaitingAttestation,
+        GameError::MatchNotAwaitingAttestation
+    );
+    require!(
+        clock.unix_timestamp > match_record.attestation_deadline,
+        GameError::AttestationWindowOpen
+    );
+
+    msg!("Closed unattested match proposal {}", game_id);
+    Ok(())
+}
+
+// Fills in who played what and when; the tickets are closed once the result is recorded, so each can only be used once
+fn open_match_record(
+    match_record: &mut Match,
+    bump: u8,
+    game_id: u64,
+    players: [Pubkey; MATCH_PLAYERS],
+    result: &MatchResult,
+    tickets: [&MatchTicket; MATCH_PLAYERS],
+    now: i64,
+) -> Result<()> {
+    require!(has_distinct_players(&players), GameError::DuplicateMatchPlayer);
+
+    let mut started_at = 0;
+    for (seat, ticket) in tickets.iter().enumerate() {
+        require!(now <= ticket.expires_at, GameError::MatchTicketExpired);
+        require!(ticket.mode == result.mode, GameError::TicketModeMismatch);
+        started_at = started_at.max(ticket.issued_at);
+        match_record.ticket_nonces[seat] = ticket.nonce;
+    }
+
+    match_record.bump = bump;
+    match_record.game_id = game_id;
+    match_record.players = players;
+    match_record.mode = result.mode;
+    match_record.contract = result.contract;
+    match_record.declarers = result.declarers;
+    match_record.started_at = started_at;
+    match_record.ended_at = now;
+    match_record.placements = result.placements;
+    match_record.tricks = result.tricks;
+    match_record.log_hash = result.log_hash;
+    match_record.status = MatchStatus::AwaitingAttestation;
+    match_record.attestations = 0;
+    match_record.recorded_at = 0;
+    match_record.disputed_by = None;
+    match_record.evidence_hash = [0; 32];
+    match_record.rejections = 0;
+    match_record.attestation_deadline = 0;
+    match_record.dispute_deadline = 0;
+    match_record.snapshots = [ScoreSnapshot::default(); MATCH_PLAYERS];
+    Ok(())
+}
+
+// Writes the result into all four scores, initializing any that do not exist yet
+fn apply_match_result(
+    match_record: &mut Match,
+    mut scores: [&mut PlayerScore; MATCH_PLAYERS],
+    bumps: [u8; MATCH_PLAYERS],
+    rent_payer: Pubkey,
+    leaderboard: &mut Leaderboard,
+    game_state: &GameState,
+    now: i64,
+) {
+    let season = game_state.current_season;
+
+    for (seat, player_score) in scores.iter_mut().enumerate() {
+        init_player_score(player_score, match_record.players[seat], bumps[seat], rent_payer);
+
+        // Settle any idle decay first so the match is rated from where the player really stands
+        apply_rating_decay(player_score, game_state, now);
+
+        match_record.snapshots[seat] = ScoreSnapshot {
+            highest_rating: player_score.highest_rating,
+            current_win_streak: player_score.current_win_streak,
+            best_win_streak: player_score.best_win_streak,
+            last_game_time: player_score.last_game_time,
+            decay_weeks_applied: player_score.decay_weeks_applied,
+            new_partner: false,
+        };
+    }
+
+    // Rating changes are computed from the pre-match ratings of all four seats
+    let won = match_record.placements.map(|placement| placement == 1);
+    let rating_changes = rating::match_rating_changes_with_k(
+        std::array::from_fn(|seat| scores[seat].current_rating),
+        std::array::from_fn(|seat| rating::player_k_factor(scores[seat].total_games_played, game_state.provisional_games)),
+        won,
+    );
+    match_record.rating_changes = rating_changes;
+
+    // Applied right away but open to dispute until the window closes
+    match_record.status = MatchStatus::Pending;
+    match_record.recorded_at = now;
+    match_record.dispute_deadline = now + game_state.dispute_window;
+    match_record.season = season;
+
+    for (seat, player_score) in scores.iter_mut().enumerate() {
+        player_score.open_matches += 1;
+        player_score.total_games_played += 1;
+        if won[seat] {
+            player_score.total_games_won += 1;
+        }
+        player_score.total_tricks_taken += match_record.tricks[seat] as u32;
+
+        *contract_games(player_score, match_record.contract) += 1;
+        if match_record.declarers & (1 << seat) != 0 {
+            player_score.contracts_bid += 1;
+            if won[seat] {
+                player_score.contracts_made += 1;
+            }
+        }
+
+        if won[seat] {
+            player_score.current_win_streak += 1;
+            player_score.best_win_streak = player_score.best_win_streak.max(player_score.current_win_streak);
+        } else {
+            player_score.current_win_streak = 0;
+        }
+
+        if let Some(partner) = match_partner(&match_record.players, &won, seat) {
+            let buckets = player_score.partner_buckets;
+            record_partner(player_score, &partner);
+            match_record.snapshots[seat].new_partner = player_score.partner_buckets != buckets;
+        }
+
+        let new_rating = rating::apply_rating_change(player_score.current_rating, rating_changes[seat]);
+        player_score.current_rating = new_rating;
+        if new_rating > player_score.highest_rating {
+            player_score.highest_rating = new_rating;
+        }
+
+        player_score.last_game_time = now;
+        player_score.decay_weeks_applied = 0;
+
+        roll_score_season(player_score, season);
+        player_score.monthly_points += match_points(won[seat]);
+        refresh_leaderboard_entry(&mut leaderboard.entries, player_score, game_state);
+    }
+
+    // Ranks are read back once every seat has moved, since later seats can push earlier ones down
+    for player_score in scores {
+        player_score.monthly_rank = leaderboard_rank(&leaderboard.entries, &player_score.player);
+    }
+}
+
+// Sets up the score of a player's first match; an existing score is left as it is
+fn init_player_score(player_score: &mut PlayerScore, player: Pubkey, bump: u8, rent_payer: Pubkey) {
     if player_score.player == Pubkey::default() {
-        player_score.player = ctx.accounts.player.key();
-        player_score.bump = ctx.bumps.player_score;
+        player_score.player = player;
+        player_score.bump = bump;
         player_score.total_games_played = 0;
         player_score.total_games_won = 0;
-        player_score.current_rating = 1000; // Starting rating
-        player_score.highest_rating = 1000;
+        player_score.current_rating = rating::STARTING_RATING;
+        player_score.highest_rating = rating::STARTING_RATING;
         player_score.monthly_rank = 0;
         player_score.last_game_time = 0;
         player_score.total_prizes_earned = 0;
+        player_score.rent_payer = rent_payer;
+        player_score.total_tricks_taken = 0;
+        player_score.partnership_games = 0;
+        player_score.solo_games = 0;
+        player_score.abondance_games = 0;
+        player_score.misere_games = 0;
+        player_score.contracts_bid = 0;
+        player_score.contracts_made = 0;
+        player_score.current_win_streak = 0;
+        player_score.best_win_streak = 0;
+        player_score.partner_buckets = 0;
+        player_score.partner_filter = [0; PARTNER_FILTER_BYTES];
+        player_score.decay_weeks_applied = 0;
+        player_score.unclaimed_prizes = 0;
+        player_score.open_matches = 0;
     }
+}
 
-    // Update score
-    player_score.total_games_played += games_played;
-    player_score.total_games_won += games_won;
-    
-    let new_rating = (player_score.current_rating as i32 + rating_change).max(0) as u32;
-    player_score.current_rating = new_rating;
-    
-    if new_rating > player_score.highest_rating {
-        player_score.highest_rating = new_rating;
+fn contract_games(player_score: &mut PlayerScore, contract: ContractType) -> &mut u32 {
+    match contract {
+        ContractType::Partnership => &mut player_score.partnership_games,
+        ContractType::Solo => &mut player_score.solo_games,
+        ContractType::Abondance => &mut player_score.abondance_games,
+        ContractType::Misere => &mut player_score.misere_games,
     }
-    
-    player_score.last_game_time = clock.unix_timestamp;
-
-    msg!("Player score updated: {} games played, {} won, rating: {}", 
-         games_played, games_won, new_rating);
-    Ok(())
 }
 
 pub fn distribute_prize(ctx: Context<DistributePrize>, amount: u64) -> Result<()> {
+    let prize_vault_info = ctx.accounts.prize_vault.to_account_info();
     let prize_vault = &mut ctx.accounts.prize_vault;
     let player_score = &mut ctx.accounts.player_score;
-    let game_state = &mut ctx.accounts.game_state;
+    let clock = Clock::get()?;
 
-    // Transfer tokens from prize vault to recipient
-    let transfer_ctx = CpiContext::new(
+    // Players can only claim what governance has awarded them
+    require!(
+        amount > 0 && amount <= player_score.unclaimed_prizes,
+        GameError::PrizeNotAwarded
+    );
+
+    require!(
+        !is_pass_suspended(&ctx.accounts.player_pass, clock.unix_timestamp),
+        GameError::PassSuspended
+    );
+    require!(
+        amount <= ctx.accounts.prize_vault_token_account.amount,
+        GameError::InsufficientTokens
+    );
+
+    // Transfer tokens from prize vault to recipient, signed by the prize vault PDA
+    let bump = prize_vault.bump;
+    let seeds = prize_vault_signer_seeds(&bump);
+    let signer = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.prize_vault_token_account.to_account_info(),
+            mint: ctx.accounts.wzn_mint.to_account_info(),
             to: ctx.accounts.recipient_token_account.to_account_info(),
-            authority: ctx.accounts.recipient.to_account_info(),
+            authority: prize_vault_info,
         },
+        signer,
     );
 
-    token::transfer(transfer_ctx, amount)?;
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.wzn_mint.decimals)?;
 
-    // Update state
-    prize_vault.total_distributed += amount;
+    // The award already counted towards the distributed totals
+    player_score.unclaimed_prizes -= amount;
     player_score.total_prizes_earned += amount;
-    game_state.total_prizes_distributed += amount;
+    prize_vault.last_distribution = clock.unix_timestamp;
 
     msg!("Prize distributed: {} WZN to {}", amount, ctx.accounts.recipient.key());
     Ok(())
 }
 
+// Grows a PlayerScore created before the whist statistics existed and fills in the appended fields
+pub fn migrate_player_score(ctx: Context<MigratePlayerScore>, player: Pubkey) -> Result<()> {
+    let score_info = ctx.accounts.player_score.to_account_info();
+
+    require!(score_info.owner == &crate::ID, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+    {
+        let data = score_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == PlayerScore::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+    }
+    require!(score_info.data_len() < PLAYER_SCORE_SPACE, GameError::ScoreAlreadyMigrated);
+
+    let shortfall = Rent::get()?
+        .minimum_balance(PLAYER_SCORE_SPACE)
+        .saturating_sub(score_info.lamports());
+    if shortfall > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: score_info.clone(),
+            },
+        );
+        system_program::transfer(transfer_ctx, shortfall)?;
+    }
+
+    score_info.realloc(PLAYER_SCORE_SPACE, true)?;
+
+    let mut data = score_info.try_borrow_mut_data()?;
+    let mut player_score = PlayerScore::try_deserialize(&mut &data[..])?;
+    init_migrated_player_score(&mut player_score);
+    player_score.try_serialize(&mut &mut data[..])?;
+
+    msg!("Player score migrated for: {}", player);
+    Ok(())
+}
+
+// Leaderboard Instructions
+// Anyone may open the current season's leaderboard; match results cannot be recorded until it exists
+pub fn open_leaderboard(ctx: Context<OpenLeaderboard>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.season = ctx.accounts.game_state.current_season;
+    leaderboard.entries = Vec::new();
+
+    msg!("Leaderboard opened for season {}", leaderboard.season);
+    Ok(())
+}
+
+// Permissionless crank for players pushed down the board by someone else's match
+pub fn sync_monthly_rank(ctx: Context<SyncMonthlyRank>, player: Pubkey) -> Result<()> {
+    let player_score = &mut ctx.accounts.player_score;
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let clock = Clock::get()?;
+
+    apply_rating_decay(player_score, &ctx.accounts.game_state, clock.unix_timestamp);
+    roll_score_season(player_score, leaderboard.season);
+
+    // Reading the board also writes back a decayed rating; players who are not on it wait for their next match
+    if leaderboard_rank(&leaderboard.entries, &player) != 0 {
+        refresh_leaderboard_entry(&mut leaderboard.entries, player_score, &ctx.accounts.game_state);
+    }
+    player_score.monthly_rank = leaderboard_rank(&leaderboard.entries, &player);
+
+    msg!("Monthly rank for {}: {}", player, player_score.monthly_rank);
+    Ok(())
+}
+
+// Match Dispute Instructions
+pub fn dispute_match(ctx: Context<DisputeMatch>, game_id: u64, evidence_hash: [u8; 32]) -> Result<()> {
+    let match_record = &mut ctx.accounts.match_record;
+    let disputer = ctx.accounts.disputer.key();
+    let clock = Clock::get()?;
+
+    require!(match_record.status == MatchStatus::Pending, GameError::MatchNotPending);
+    require!(match_record.players.contains(&disputer), GameError::NotSeatedPlayer);
+    require!(
+        is_dispute_window_open(match_record, clock.unix_timestamp),
+        GameError::DisputeWindowClosed
+    );
+
+    match_record.status = MatchStatus::Disputed;
+    match_record.disputed_by = Some(disputer);
+    match_record.evidence_hash = evidence_hash;
+
+    msg!("Match {} disputed by: {}", game_id, disputer);
+    Ok(())
+}
+
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, game_id: u64, void: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let arbiter = ctx.accounts.arbiter.key();
+    let match_record = &mut ctx.accounts.match_record;
+    let contested = match_record.status == MatchStatus::Contested;
+
+    require!(
+        contested || match_record.status == MatchStatus::Disputed,
+        GameError::MatchNotDisputed
+    );
+
+    let bumps = [
+        ctx.bumps.player_score_0,
+        ctx.bumps.player_score_1,
+        ctx.bumps.player_score_2,
+        ctx.bumps.player_score_3,
+    ];
+    let scores = [
+        &mut ctx.accounts.player_score_0,
+        &mut ctx.accounts.player_score_1,
+        &mut ctx.accounts.player_score_2,
+        &mut ctx.accounts.player_score_3,
+    ];
+    for (seat, player_score) in scores.into_iter().enumerate() {
+        init_player_score(player_score, match_record.players[seat], bumps[seat], arbiter);
+    }
+
+    // A contested result never touched the scores, so the ruling either records it now or drops it
+    if contested {
+        if void {
+            match_record.status = MatchStatus::Voided;
+            msg!("Contested match {} voided by arbiter: {}", game_id, arbiter);
+            return Ok(());
+        }
+
+        require!(
+            match_record.season == ctx.accounts.game_state.current_season,
+            GameError::MatchSeasonClosed
+        );
+        apply_match_result(
+            &mut ctx.accounts.match_record,
+            [
+                &mut ctx.accounts.player_score_0,
+                &mut ctx.accounts.player_score_1,
+                &mut ctx.accounts.player_score_2,
+                &mut ctx.accounts.player_score_3,
+            ],
+            bumps,
+            arbiter,
+            &mut ctx.accounts.leaderboard,
+            &ctx.accounts.game_state,
+            clock.unix_timestamp,
+        );
+
+        // The arbiter has already ruled, so the result does not reopen for dispute
+        ctx.accounts.match_record.status = MatchStatus::Final;
+        release_open_match([
+            &mut ctx.accounts.player_score_0,
+            &mut ctx.accounts.player_score_1,
+            &mut ctx.accounts.player_score_2,
+            &mut ctx.accounts.player_score_3,
+        ]);
+
+        msg!("Contested match {} recorded by arbiter: {}", game_id, arbiter);
+        return Ok(());
+    }
+
+    // Either way the match stops holding the scores open
+    release_open_match([
+        &mut ctx.accounts.player_score_0,
+        &mut ctx.accounts.player_score_1,
+        &mut ctx.accounts.player_score_2,
+        &mut ctx.accounts.player_score_3,
+    ]);
+
+    if !void {
+        match_record.status = MatchStatus::Final;
+        msg!("Dispute on match {} rejected, result is final", game_id);
+        return Ok(());
+    }
+
+    let won = match_record.placements.map(|placement| placement == 1);
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let mut scores = [
+        &mut ctx.accounts.player_score_0,
+        &mut ctx.accounts.player_score_1,
+        &mut ctx.accounts.player_score_2,
+        &mut ctx.accounts.player_score_3,
+    ];
+
+    // Undo the counted deltas of apply_match_result, then put back what the match overwrote
+    for (seat, player_score) in scores.iter_mut().enumerate() {
+        player_score.total_games_played = player_score.total_games_played.saturating_sub(1);
+        if won[seat] {
+            player_score.total_games_won = player_score.total_games_won.saturating_sub(1);
+        }
+        player_score.total_tricks_taken = player_score
+            .total_tricks_taken
+            .saturating_sub(match_record.tricks[seat] as u32);
+
+        let games = contract_games(player_score, match_record.contract);
+        *games = games.saturating_sub(1);
+        if match_record.declarers & (1 << seat) != 0 {
+            player_score.contracts_bid = player_score.contracts_bid.saturating_sub(1);
+            if won[seat] {
+                player_score.contracts_made = player_score.contracts_made.saturating_sub(1);
+            }
+        }
+
+        player_score.current_rating =
+            rating::apply_rating_change(player_score.current_rating, -match_record.rating_changes[seat]);
+
+        // Later matches have built on the peak, streaks and partners, so they only come back while this is the last game
+        if player_score.last_game_time == match_record.recorded_at {
+            restore_score_snapshot(player_score, &match_record.snapshots[seat], &match_record.players, &won, seat);
+        }
+
+        // Monthly points only come back off while the player is still in the match's season;
+        // otherwise the closed season's board entry is corrected on its own
+        if player_score.season == match_record.season {
+            player_score.monthly_points = player_score.monthly_points.saturating_sub(match_points(won[seat]));
+            refresh_leaderboard_entry(&mut leaderboard.entries, player_score, &ctx.accounts.game_state);
+        } else if let Some(entry) = leaderboard.entries.iter().find(|entry| entry.player == player_score.player).copied() {
+            upsert_leaderboard_entry(
+                &mut leaderboard.entries,
+                LeaderboardEntry {
+                    monthly_points: entry.monthly_points.saturating_sub(match_points(won[seat])),
+                    ..entry
+                },
+            );
+        }
+    }
+
+    // A season closed while the match was open keeps standings that must match the corrected board
+    if match_record.season != ctx.accounts.game_state.current_season {
+        let season_record = ctx.accounts.season_record.as_mut().ok_or(GameError::SeasonRecordRequired)?;
+        season_record.standings = leaderboard.entries.clone();
+    }
+
+    for player_score in scores {
+        if player_score.season == match_record.season {
+            player_score.monthly_rank = leaderboard_rank(&leaderboard.entries, &player_score.player);
+        }
+    }
+
+    match_record.status = MatchStatus::Voided;
+
+    msg!("Match {} voided by arbiter: {}", game_id, arbiter);
+    Ok(())
+}
+
+// Permissionless crank: undisputed results become final once the window has passed
+pub fn finalize_match(ctx: Context<FinalizeMatch>, game_id: u64) -> Result<()> {
+    let match_record = &mut ctx.accounts.match_record;
+    let clock = Clock::get()?;
+
+    require!(match_record.status == MatchStatus::Pending, GameError::MatchNotPending);
+    require!(
+        !is_dispute_window_open(match_record, clock.unix_timestamp),
+        GameError::DisputeWindowOpen
+    );
+
+    match_record.status = MatchStatus::Final;
+    release_open_match([
+        &mut ctx.accounts.player_score_0,
+        &mut ctx.accounts.player_score_1,
+        &mut ctx.accounts.player_score_2,
+        &mut ctx.accounts.player_score_3,
+    ]);
+
+    msg!("Match {} finalized", game_id);
+    Ok(())
+}
+
+fn restore_score_snapshot(
+    player_score: &mut PlayerScore,
+    snapshot: &ScoreSnapshot,
+    players: &[Pubkey; MATCH_PLAYERS],
+    won: &[bool; MATCH_PLAYERS],
+    seat: usize,
+) {
+    player_score.highest_rating = snapshot.highest_rating;
+    player_score.current_win_streak = snapshot.current_win_streak;
+    player_score.best_win_streak = snapshot.best_win_streak;
+    player_score.last_game_time = snapshot.last_game_time;
+    player_score.decay_weeks_applied = snapshot.decay_weeks_applied;
+
+    if snapshot.new_partner {
+        if let Some(partner) = match_partner(players, won, seat) {
+            forget_partner(player_score, &partner);
+        }
+    }
+}
+
+fn release_open_match(scores: [&mut PlayerScore; MATCH_PLAYERS]) {
+    for player_score in scores {
+        player_score.open_matches = player_score.open_matches.saturating_sub(1);
+    }
+}
+
+// Account Cleanup Instructions
+pub fn close_player_pass(ctx: Context<ClosePlayerPass>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        can_close_player_pass(&ctx.accounts.player_pass, &ctx.accounts.game_state, clock.unix_timestamp),
+        GameError::PassStillInUse
+    );
+
+    // Burn the soulbound mirror so a reopened pass starts from an empty token account
+    sync_optional_pass_token(
+        &ctx.accounts.player_pass,
+        &ctx.accounts.pass_mint,
+        &ctx.accounts.pass_token_account,
+        &ctx.accounts.pass_authority,
+        &ctx.accounts.pass_token_program,
+        clock.unix_timestamp,
+    )?;
+
+    msg!("Player pass closed for player: {}", ctx.accounts.player.key());
+    msg!("Rent returned to: {}", ctx.accounts.rent_payer.key());
+    Ok(())
+}
+
+pub fn close_inactive_player_pass(ctx: Context<CloseInactivePlayerPass>, player: Pubkey) -> Result<()> {
+    let player_pass = &ctx.accounts.player_pass;
+    let game_state = &ctx.accounts.game_state;
+    let clock = Clock::get()?;
+
+    require!(
+        can_close_player_pass(player_pass, game_state, clock.unix_timestamp),
+        GameError::PassStillInUse
+    );
+    require!(
+        is_inactive_for_close(player_pass.last_activity.max(player_pass.pass_end_time), game_state, clock.unix_timestamp),
+        GameError::AccountNotInactive
+    );
+
+    sync_optional_pass_token(
+        player_pass,
+        &ctx.accounts.pass_mint,
+        &ctx.accounts.pass_token_account,
+        &ctx.accounts.pass_authority,
+        &ctx.accounts.pass_token_program,
+        clock.unix_timestamp,
+    )?;
+
+    msg!("Inactive player pass closed for player: {}", player);
+    msg!("Rent returned to: {}", ctx.accounts.rent_payer.key());
+    Ok(())
+}
+
+pub fn close_player_score(ctx: Context<ClosePlayerScore>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        !player_pass_blocks_close(&ctx.accounts.player_pass, &ctx.accounts.game_state, clock.unix_timestamp)?,
+        GameError::PassStillInUse
+    );
+    check_player_score_closable(&ctx.accounts.player_score)?;
+
+    msg!("Player score closed for player: {}", ctx.accounts.player.key());
+    msg!("Rent returned to: {}", ctx.accounts.rent_payer.key());
+    Ok(())
+}
+
+pub fn close_inactive_player_score(ctx: Context<CloseInactivePlayerScore>, player: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        !player_pass_blocks_close(&ctx.accounts.player_pass, &ctx.accounts.game_state, clock.unix_timestamp)?,
+        GameError::PassStillInUse
+    );
+    require!(
+        is_inactive_for_close(ctx.accounts.player_score.last_game_time, &ctx.accounts.game_state, clock.unix_timestamp),
+        GameError::AccountNotInactive
+    );
+    check_player_score_closable(&ctx.accounts.player_score)?;
+
+    msg!("Inactive player score closed for player: {}", player);
+    msg!("Rent returned to: {}", ctx.accounts.rent_payer.key());
+    Ok(())
+}
+
+// Closing a score must not forfeit an award or stop an arbiter voiding an open match
+fn check_player_score_closable(player_score: &PlayerScore) -> Result<()> {
+    require!(player_score.open_matches == 0, GameError::ScoreHasOpenMatches);
+    require!(player_score.unclaimed_prizes == 0, GameError::UnclaimedPrizes);
+    Ok(())
+}
+
+// A score stays open while the player's pass still exists and cannot be closed
+fn player_pass_blocks_close(player_pass: &UncheckedAccount, game_state: &GameState, now: i64) -> Result<bool> {
+    if player_pass.data_is_empty() {
+        return Ok(false);
+    }
+    require_keys_eq!(*player_pass.owner, crate::ID, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+    let data = player_pass.try_borrow_data()?;
+    let player_pass = PlayerPass::try_deserialize(&mut &data[..])?;
+    Ok(!can_close_player_pass(&player_pass, game_state, now))
+}
+
 // Monthly Reset Instruction
 pub fn monthly_reset(ctx: Context<MonthlyReset>) -> Result<()> {
     let game_state = &mut ctx.accounts.game_state;
+    let season_record = &mut ctx.accounts.season_record;
+    let next_leaderboard = &mut ctx.accounts.next_leaderboard;
     let clock = Clock::get()?;
 
     // Check if monthly reset is needed
@@ -426,8 +2291,25 @@ pub fn monthly_reset(ctx: Context<MonthlyReset>) -> Result<()> {
         GameError::MonthlyResetNotReady
     );
 
+    // Record the closing season; player monthly counters restart lazily when their scores are next touched.
+    // Matches still open to dispute can change the standings later, and resolve_dispute patches them here.
+    season_record.bump = ctx.bumps.season_record;
+    season_record.season = game_state.current_season;
+    season_record.started_at = game_state.last_monthly_reset;
+    season_record.ended_at = clock.unix_timestamp;
+    season_record.standings = ctx.accounts.leaderboard.entries.clone();
+    season_record.total_burned = game_state.total_burned - game_state.season_start_burned;
+    season_record.prize_pool = ctx.accounts.prize_vault_token_account.amount;
+
     game_state.last_monthly_reset = clock.unix_timestamp;
+    game_state.current_season += 1;
+    game_state.season_start_burned = game_state.total_burned;
+
+    next_leaderboard.bump = ctx.bumps.next_leaderboard;
+    next_leaderboard.season = game_state.current_season;
+    next_leaderboard.entries = Vec::new();
 
     msg!("Monthly reset completed at timestamp: {}", clock.unix_timestamp);
+    msg!("Season {} closed, season {} started", season_record.season, game_state.current_season);
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,308 @@
+// Elo rating engine for whist tables, in integer math so it runs deterministically on-chain.
+// Free of Anchor types so off-chain tools can link the crate and reproduce every rating change.
+
+pub const STARTING_RATING: u32 = 1000;
+pub const MAX_RATING_DIFF: i32 = 800; // Wider gaps are scored as if they were 800 points
+pub const EXPECTED_SCORE_SCALE: i32 = 1000; // Expected scores are in thousandths
+pub const PROVISIONAL_K_FACTOR: i32 = 64; // Used until a player has finished the provisional period
+pub const RATING_DECAY_PER_WEEK: u32 = 10;
+pub const WEEK_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+const EXPECTED_SCORE_STEP: i32 = 25;
+
+// Expected score of the stronger side per 25 points of rating difference: 1000 / (1 + 10^(-diff / 400))
+const EXPECTED_SCORE_TABLE: [i32; 33] = [
+    500, 536, 571, 606, 640, 673, 703, 733, 760, 785, 808, 830, 849, 867, 882, 896, 909, 920, 930, 939,
+    947, 954, 960, 965, 969, 973, 977, 980, 983, 985, 987, 989, 990,
+];
+
+// New players move fast until their rating settles, veterans move slowly
+pub fn k_factor(games_played: u32) -> i32 {
+    match games_played {
+        0..=29 => 40,
+        30..=99 => 32,
+        _ => 24,
+    }
+}
+
+// Provisional players swing harder so their rating finds its level in a handful of games
+pub fn player_k_factor(games_played: u32, provisional_games: u32) -> i32 {
+    if games_played < provisional_games {
+        PROVISIONAL_K_FACTOR
+    } else {
+        k_factor(games_played)
+    }
+}
+
+// Expected score, in thousandths, of a side rated `rating_diff` points above its opponents
+pub fn expected_score(rating_diff: i32) -> i32 {
+    let diff = rating_diff.clamp(-MAX_RATING_DIFF, MAX_RATING_DIFF);
+    let magnitude = diff.abs();
+    let index = (magnitude / EXPECTED_SCORE_STEP) as usize;
+    let remainder = magnitude % EXPECTED_SCORE_STEP;
+
+    // Linear interpolation between the two surrounding table entries
+    let lower = EXPECTED_SCORE_TABLE[index];
+    let upper = EXPECTED_SCORE_TABLE[(index + 1).min(EXPECTED_SCORE_TABLE.len() - 1)];
+    let score = lower + (upper - lower) * remainder / EXPECTED_SCORE_STEP;
+
+    if diff >= 0 {
+        score
+    } else {
+        EXPECTED_SCORE_SCALE - score
+    }
+}
+
+// A partnership plays at the average of its members' ratings
+pub fn team_rating(ratings: &[u32]) -> u32 {
+    if ratings.is_empty() {
+        return STARTING_RATING;
+    }
+    (ratings.iter().map(|&r| r as u64).sum::<u64>() / ratings.len() as u64) as u32
+}
+
+// K * (actual - expected), rounded half away from zero
+pub fn rating_delta(k: i32, won: bool, expected: i32) -> i32 {
+    let actual = if won { EXPECTED_SCORE_SCALE } else { 0 };
+    let scaled = k * (actual - expected);
+    let half = EXPECTED_SCORE_SCALE / 2;
+    if scaled >= 0 {
+        (scaled + half) / EXPECTED_SCORE_SCALE
+    } else {
+        (scaled - half) / EXPECTED_SCORE_SCALE
+    }
+}
+
+// Rating change for every seat. Winners form one side and everybody else the other, which covers
+// 2v2 partnerships as well as solo contracts played one against three; in the latter the three
+// share the solo seat's change between them, so every table is zero-sum.
+pub fn match_rating_changes<const N: usize>(ratings: [u32; N], games_played: [u32; N], won: [bool; N]) -> [i32; N] {
+    match_rating_changes_with_k(ratings, games_played.map(k_factor), won)
+}
+
+// Same as match_rating_changes with the K-factor of every seat chosen by the caller
+pub fn match_rating_changes_with_k<const N: usize>(ratings: [u32; N], k_factors: [i32; N], won: [bool; N]) -> [i32; N] {
+    let mut winners = Vec::with_capacity(N);
+    let mut losers = Vec::with_capacity(N);
+    for seat in 0..N {
+        if won[seat] {
+            winners.push(ratings[seat]);
+        } else {
+            losers.push(ratings[seat]);
+        }
+    }
+
+    let winner_rating = team_rating(&winners) as i32;
+    let loser_rating = team_rating(&losers) as i32;
+
+    let mut changes = [0; N];
+    for seat in 0..N {
+        let expected = if won[seat] {
+            expected_score(winner_rating - loser_rating)
+        } else {
+            expected_score(loser_rating - winner_rating)
+        };
+        changes[seat] = rating_delta(k_factors[seat], won[seat], expected);
+    }
+
+    // The smaller side's change is split across the larger side, earlier seats taking any remainder
+    if !winners.is_empty() && !losers.is_empty() && winners.len() != losers.len() {
+        let smaller_won = winners.len() < losers.len();
+        let total: i32 = (0..N).filter(|&seat| won[seat] == smaller_won).map(|seat| changes[seat]).sum();
+        let larger_size = winners.len().max(losers.len()) as i32;
+        let share = -total / larger_size;
+        let mut remainder = -total % larger_size;
+        for seat in (0..N).filter(|&seat| won[seat] != smaller_won) {
+            changes[seat] = share + remainder.signum();
+            remainder -= remainder.signum();
+        }
+    }
+    changes
+}
+
+// Ratings never drop below zero
+pub fn apply_rating_change(rating: u32, change: i32) -> u32 {
+    if change >= 0 {
+        rating.saturating_add(change as u32)
+    } else {
+        rating.saturating_sub(change.unsigned_abs())
+    }
+}
+
+// Full weeks idle beyond the grace period
+pub fn decay_weeks(idle_seconds: i64, grace_weeks: u32) -> u32 {
+    let idle_weeks = (idle_seconds.max(0) / WEEK_SECONDS) as u32;
+    idle_weeks.saturating_sub(grace_weeks)
+}
+
+// Pulls a rating down towards the floor; ratings already at or below it are left alone
+pub fn decay_rating(rating: u32, floor: u32, weeks: u32) -> u32 {
+    if rating <= floor {
+        return rating;
+    }
+    rating.saturating_sub(weeks.saturating_mul(RATING_DECAY_PER_WEEK)).max(floor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_score_is_even_for_equal_teams() {
+        assert_eq!(expected_score(0), 500);
+    }
+
+    #[test]
+    fn expected_scores_of_both_sides_sum_to_one() {
+        for diff in [1, 13, 25, 137, 400, 799, 800, 1500] {
+            assert_eq!(expected_score(diff) + expected_score(-diff), EXPECTED_SCORE_SCALE);
+        }
+    }
+
+    #[test]
+    fn expected_score_matches_the_elo_curve() {
+        assert_eq!(expected_score(200), 760);
+        assert_eq!(expected_score(400), 909);
+        assert_eq!(expected_score(-400), 91);
+        // Interpolated between 100 (640) and 125 (673)
+        assert_eq!(expected_score(110), 653);
+    }
+
+    #[test]
+    fn expected_score_is_capped() {
+        assert_eq!(expected_score(5000), 990);
+        assert_eq!(expected_score(-5000), 10);
+    }
+
+    #[test]
+    fn expected_score_never_decreases() {
+        let mut previous = expected_score(-MAX_RATING_DIFF - 1);
+        for diff in -MAX_RATING_DIFF..=MAX_RATING_DIFF {
+            let score = expected_score(diff);
+            assert!(score >= previous);
+            previous = score;
+        }
+    }
+
+    #[test]
+    fn k_factor_shrinks_with_experience() {
+        assert_eq!(k_factor(0), 40);
+        assert_eq!(k_factor(29), 40);
+        assert_eq!(k_factor(30), 32);
+        assert_eq!(k_factor(99), 32);
+        assert_eq!(k_factor(100), 24);
+    }
+
+    #[test]
+    fn provisional_players_use_the_larger_k_factor() {
+        assert_eq!(player_k_factor(0, 10), PROVISIONAL_K_FACTOR);
+        assert_eq!(player_k_factor(9, 10), PROVISIONAL_K_FACTOR);
+        assert_eq!(player_k_factor(10, 10), 40);
+        assert_eq!(player_k_factor(0, 0), 40);
+    }
+
+    #[test]
+    fn team_rating_is_the_average() {
+        assert_eq!(team_rating(&[1000, 1200]), 1100);
+        assert_eq!(team_rating(&[1001, 1000]), 1000);
+        assert_eq!(team_rating(&[]), STARTING_RATING);
+    }
+
+    #[test]
+    fn rating_delta_rounds_symmetrically() {
+        assert_eq!(rating_delta(40, true, 500), 20);
+        assert_eq!(rating_delta(40, false, 500), -20);
+        // 32 * 0.487 = 15.584
+        assert_eq!(rating_delta(32, true, 513), 16);
+        assert_eq!(rating_delta(32, false, 487), -16);
+    }
+
+    #[test]
+    fn even_partnerships_trade_half_of_k() {
+        let changes = match_rating_changes([1000; 4], [0; 4], [true, false, true, false]);
+        assert_eq!(changes, [20, -20, 20, -20]);
+    }
+
+    #[test]
+    fn underdogs_gain_more_than_favourites() {
+        let ratings = [1200, 1000, 1200, 1000];
+        let games = [200; 4];
+        let favourites_win = match_rating_changes(ratings, games, [true, false, true, false]);
+        let underdogs_win = match_rating_changes(ratings, games, [false, true, false, true]);
+        assert_eq!(favourites_win, [6, -6, 6, -6]);
+        assert_eq!(underdogs_win, [-18, 18, -18, 18]);
+    }
+
+    #[test]
+    fn partners_share_the_team_average() {
+        // A strong and a weak player facing two average players is an even match
+        let changes = match_rating_changes([1400, 1200, 1000, 1200], [200; 4], [true, false, true, false]);
+        assert_eq!(changes, [12, -12, 12, -12]);
+    }
+
+    #[test]
+    fn k_factor_is_per_player() {
+        let changes = match_rating_changes([1000; 4], [0, 200, 50, 200], [true, false, true, false]);
+        assert_eq!(changes, [20, -12, 16, -12]);
+    }
+
+    #[test]
+    fn provisional_seats_move_further() {
+        let k_factors = [0, 200, 5, 200].map(|games| player_k_factor(games, 10));
+        let changes = match_rating_changes_with_k([1000; 4], k_factors, [true, false, true, false]);
+        assert_eq!(changes, [32, -12, 32, -12]);
+    }
+
+    #[test]
+    fn solo_contracts_score_one_against_three() {
+        let changes = match_rating_changes([1000; 4], [200; 4], [true, false, false, false]);
+        assert_eq!(changes, [12, -4, -4, -4]);
+    }
+
+    #[test]
+    fn a_lost_solo_is_shared_by_the_defenders() {
+        let changes = match_rating_changes([1000; 4], [200; 4], [false, true, true, true]);
+        assert_eq!(changes, [-12, 4, 4, 4]);
+    }
+
+    #[test]
+    fn solo_remainders_keep_the_table_zero_sum() {
+        // 20 points split three ways: the first two defenders lose the extra point
+        let changes = match_rating_changes([1000; 4], [0, 200, 200, 200], [true, false, false, false]);
+        assert_eq!(changes, [20, -7, -7, -6]);
+
+        for ratings in [[1300, 1000, 1100, 900], [800, 1500, 1200, 1000], [1000, 1000, 1000, 2000]] {
+            for soloist in 0..4 {
+                for won in [true, false] {
+                    let outcome = std::array::from_fn(|seat| (seat == soloist) == won);
+                    let changes = match_rating_changes(ratings, [0, 40, 120, 5], outcome);
+                    assert_eq!(changes.iter().sum::<i32>(), 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ratings_are_floored_at_zero() {
+        assert_eq!(apply_rating_change(10, -20), 0);
+        assert_eq!(apply_rating_change(1000, 16), 1016);
+        assert_eq!(apply_rating_change(1000, -16), 984);
+    }
+
+    #[test]
+    fn decay_starts_after_the_grace_period() {
+        assert_eq!(decay_weeks(0, 4), 0);
+        assert_eq!(decay_weeks(4 * WEEK_SECONDS, 4), 0);
+        assert_eq!(decay_weeks(5 * WEEK_SECONDS - 1, 4), 0);
+        assert_eq!(decay_weeks(5 * WEEK_SECONDS, 4), 1);
+        assert_eq!(decay_weeks(-WEEK_SECONDS, 0), 0);
+    }
+
+    #[test]
+    fn decay_stops_at_the_floor() {
+        assert_eq!(decay_rating(1200, 1000, 3), 1170);
+        assert_eq!(decay_rating(1200, 1000, 50), 1000);
+        assert_eq!(decay_rating(900, 1000, 5), 900);
+        assert_eq!(decay_rating(1200, 1000, u32::MAX), 1000);
+    }
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::errors::GameError;
 
@@ -8,18 +8,67 @@ pub const MONTHLY_SECONDS: i64 = 30 * 24 * 60 * 60; // 30 days in seconds
 pub const DAO_INACTIVITY_THRESHOLD: i64 = 6 * 30 * 24 * 60 * 60; // 6 months
 pub const EMERGENCY_UNLOCK_DELAY: i64 = 7 * 24 * 60 * 60; // 7 days
 pub const MINIMUM_BURN_AMOUNT: u64 = 1_000_000; // 1 WZN (assuming 6 decimals)
+pub const MAX_PREPAID_MONTHS_LIMIT: u8 = 24; // Upper bound for the configurable prepaid horizon
 pub const MAX_EMERGENCY_UNLOCK_PERCENTAGE: u64 = 25; // 25% max emergency unlock
 pub const DAO_QUORUM_PERCENTAGE: u64 = 60; // 60% quorum for DAO votes
 pub const EMERGENCY_QUORUM_PERCENTAGE: u64 = 80; // 80% quorum for emergency votes
+pub const EMERGENCY_APPROVAL_WINDOW: i64 = 7 * 24 * 60 * 60; // Approvals for an emergency unlock lapse after 7 days
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_FEE_SPONSORS: usize = 10; // Approved sponsor fee payers
+pub const DEFAULT_FREE_GAMES_PER_PERIOD: u8 = 2; // Free casual games per month without a pass
+pub const MATCH_TICKET_VALIDITY: i64 = 4 * 60 * 60; // 4 hours to play and report a match
+pub const MATCH_ATTESTATION_WINDOW: i64 = 24 * 60 * 60; // 1 day for the table to attest a proposed result
+pub const MAX_MODERATORS: usize = 20;
+pub const MAX_GAME_SERVERS: usize = 10;
+pub const MATCH_PLAYERS: usize = 4; // Seats at a whist table
+pub const DEFAULT_ATTESTATION_THRESHOLD: u8 = 3; // Seats that must co-sign a player-proposed result
+pub const MAX_ARBITERS: usize = 10;
+pub const PARTNER_FILTER_BYTES: usize = 32; // 256-bit filter of partners seen
+pub const LEADERBOARD_SIZE: usize = 100;
+pub const MATCH_WIN_POINTS: u32 = 3; // Monthly points for a won match
+pub const MATCH_PLAY_POINTS: u32 = 1; // Monthly points for a lost match
+pub const DEFAULT_DISPUTE_WINDOW: i64 = 24 * 60 * 60; // 1 day to dispute a recorded result
+pub const MAX_DISPUTE_WINDOW: i64 = 30 * 24 * 60 * 60;
+pub const DEFAULT_PROVISIONAL_GAMES: u32 = 10; // Games before a rating settles and reaches the leaderboard
+pub const MAX_PROVISIONAL_GAMES: u32 = 100;
+pub const DEFAULT_RATING_DECAY_WEEKS: u32 = 4; // Idle weeks before a rating starts to decay
+pub const DEFAULT_RATING_FLOOR: u32 = crate::rating::STARTING_RATING; // Decay never pulls a rating below this
+pub const MAX_SUSPENSION_DURATION: i64 = 365 * 24 * 60 * 60; // 1 year per suspension
+pub const DEFAULT_INACTIVITY_CLOSE_MONTHS: u8 = 6; // Idle months before anyone may close a player's accounts
+pub const DEFAULT_BURN_BPS: u16 = 0; // Share of each purchase destroyed outright; the rest is locked
+pub const MAX_BURN_ROUTES: usize = 3; // One leg per destination vault
+pub const MAX_PRICE_STEPS: usize = 8; // Scheduled pass cost changes kept on-chain
+pub const REFERENCE_PRICE_DECIMALS: i32 = 6; // Reference currency amounts are in millionths (e.g. micro-USD)
 
 // Seeds for PDAs
 pub const GAME_STATE_SEED: &[u8] = b"game_state";
 pub const BURN_VAULT_SEED: &[u8] = b"burn_vault";
 pub const PRIZE_VAULT_SEED: &[u8] = b"prize_vault";
+pub const BURN_VAULT_TOKEN_SEED: &[u8] = b"burn_vault_token";
+pub const PRIZE_VAULT_TOKEN_SEED: &[u8] = b"prize_vault_token";
+pub const RECOVERY_VAULT_SEED: &[u8] = b"recovery_vault";
+pub const RECOVERY_VAULT_TOKEN_SEED: &[u8] = b"recovery_vault_token";
 pub const DAO_GOVERNANCE_SEED: &[u8] = b"dao_governance";
 pub const EMERGENCY_RECOVERY_SEED: &[u8] = b"emergency_recovery";
 pub const PLAYER_PASS_SEED: &[u8] = b"player_pass";
 pub const PLAYER_SCORE_SEED: &[u8] = b"player_score";
+pub const PASS_TIER_SEED: &[u8] = b"pass_tier";
+pub const FEE_CONFIG_SEED: &[u8] = b"fee_config";
+pub const MATCH_TICKET_SEED: &[u8] = b"match_ticket";
+pub const MATCH_SEED: &[u8] = b"match";
+pub const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+pub const SEASON_SEED: &[u8] = b"season";
+pub const ROLE_REGISTRY_SEED: &[u8] = b"role_registry";
+pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
+pub const PASS_MINT_SEED: &[u8] = b"pass_mint";
+pub const PASS_AUTHORITY_SEED: &[u8] = b"pass_authority";
+
+// Pass token metadata
+pub const PASS_TOKEN_NAME: &str = "WZN Monthly Pass";
+pub const PASS_TOKEN_SYMBOL: &str = "WZNPASS";
+pub const PASS_TOKEN_URI: &str = "";
+pub const PASS_TIER_FIELD: &str = "tier";
+pub const PASS_EXPIRY_FIELD: &str = "expires_at";
 
 #[account]
 pub struct GameState {
@@ -32,8 +81,34 @@ pub struct GameState {
     pub total_prizes_distributed: u64,
     pub last_monthly_reset: i64,
     pub emergency_mode: bool,
+    pub token_program: Pubkey, // SPL Token or Token-2022, whichever owns the WZN mint
+    pub max_prepaid_months: u8, // Furthest a pass may be prepaid into the future
+    pub free_games_per_period: u8, // Free casual games per monthly period for players without a pass
+    pub burn_bps: u16, // Share of each purchase burned via the token program, in basis points
+    pub total_destroyed: u64, // Permanently removed from supply
+    pub total_locked: u64, // Routed into vaults
+    pub burn_routes: Vec<BurnRoute>, // How the locked share is split between vaults; bps sum to 10000
+    pub pricing_mode: PricingMode,
+    pub price_feed: Pubkey, // Feed used to convert the reference pass cost in Pegged mode
+    pub reference_pass_cost: u64, // Monthly pass cost in the reference currency, REFERENCE_PRICE_DECIMALS
+    pub max_price_age: i64, // Seconds before a feed price is considered stale
+    pub max_price_confidence_bps: u16, // Widest acceptable confidence interval relative to price
+    pub min_pass_cost: u64, // Lower bound for the converted WZN cost
+    pub max_pass_cost: u64, // Upper bound for the converted WZN cost
+    pub price_schedule: Vec<PriceStep>, // Upcoming monthly pass cost changes, sorted by effective time
+    pub inactivity_close_months: u8, // Idle months before player accounts can be closed by the crank
+    pub attestation_threshold: u8, // Seats that must sign a player-proposed match result
+    pub dispute_window: i64, // Seconds a recorded match result stays open to dispute
+    pub current_season: u32, // Advanced by every monthly reset
+    pub season_start_burned: u64, // total_burned when the current season started
+    pub provisional_games: u32, // Games played at the provisional K-factor and kept off the leaderboard
+    pub rating_decay_weeks: u32, // Idle weeks after the last game before decay kicks in
+    pub rating_floor: u32,
 }
 
+pub const GAME_STATE_SPACE: usize = 8 + 1 + 32 + 32 + 8 + 1 + 8 + 8 + 8 + 1 + 32 + 1 + 1 + 2 + 8 + 8 + 4
+    + (1 + 2) * MAX_BURN_ROUTES + 1 + 32 + 8 + 8 + 2 + 8 + 8 + 4 + (8 + 8) * MAX_PRICE_STEPS + 1 + 1 + 8 + 4 + 8 + 4 + 4 + 4;
+
 #[account]
 pub struct BurnVault {
     pub bump: u8,
@@ -44,6 +119,8 @@ pub struct BurnVault {
     pub minimum_balance_threshold: u64, // 10M WZN
     pub is_initialized: bool,
     pub unlock_delay: i64, // Delay before emergency unlock can execute
+    pub token_account: Pubkey, // PDA-owned WZN token account holding locked tokens
+    pub token_account_bump: u8,
 }
 
 #[account]
@@ -53,6 +130,18 @@ pub struct PrizeVault {
     pub total_distributed: u64,
     pub last_distribution: i64,
     pub is_initialized: bool,
+    pub token_account: Pubkey, // PDA-owned WZN token account holding prize funds
+    pub token_account_bump: u8,
+}
+
+#[account]
+pub struct RecoveryVault {
+    pub bump: u8,
+    pub total_received: u64,
+    pub is_initialized: bool,
+    pub token_account: Pubkey, // PDA-owned WZN token account holding recovery funds
+    pub token_account_bump: u8,
+    pub total_withdrawn: u64, // Paid out through WithdrawRecoveryVault proposals
 }
 
 #[account]
@@ -76,6 +165,62 @@ pub struct EmergencyRecovery {
     pub is_initialized: bool,
     pub emergency_active: bool,
     pub emergency_start_time: i64,
+    pub pending_unlock_amount: u64, // Unlock the approvals below are for; 0 when none is pending
+    pub pending_unlock_recipient: Pubkey,
+    pub pending_unlock_requested_at: i64,
+    pub unlock_approvals: Vec<Pubkey>, // Backup members who approved the pending unlock
+}
+
+#[account]
+pub struct FeeConfig {
+    pub bump: u8,
+    pub fee_mode: FeeMode,
+    pub approved_sponsors: Vec<Pubkey>, // Wallets allowed to pay rent for players
+    pub is_initialized: bool,
+}
+
+#[account]
+pub struct RoleRegistry {
+    pub bump: u8,
+    pub moderators: Vec<Pubkey>, // May suspend and reinstate passes
+    pub is_initialized: bool,
+    pub game_servers: Vec<Pubkey>, // May report match results
+    pub arbiters: Vec<Pubkey>, // May confirm or void disputed match results
+}
+
+// Governance-run stand-in for a Pyth-style price account: price * 10^expo reference units per WZN
+#[account]
+pub struct PriceFeed {
+    pub bump: u8,
+    pub updater: Pubkey, // Only key allowed to publish prices
+    pub price: u64,
+    pub confidence: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+    pub is_initialized: bool,
+}
+
+#[account]
+pub struct PassTier {
+    pub bump: u8,
+    pub tier_id: u8,
+    pub cost_bps: u32, // Price per period in basis points of GameState.monthly_pass_cost
+    pub duration: i64, // Seconds of access granted per period
+    pub ranked_access: bool,
+    pub tournament_entry: bool,
+    pub prize_eligible: bool,
+    pub is_active: bool, // Inactive tiers can no longer be purchased
+}
+
+// Settings applied to a tier by configure_pass_tier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PassTierConfig {
+    pub cost_bps: u32,
+    pub duration: i64,
+    pub ranked_access: bool,
+    pub tournament_entry: bool,
+    pub prize_eligible: bool,
+    pub is_active: bool,
 }
 
 #[account]
@@ -85,10 +230,70 @@ pub struct PlayerPass {
     pub pass_start_time: i64,
     pub pass_end_time: i64,
     pub is_active: bool,
-    pub total_passes_purchased: u32,
+    pub total_passes_purchased: u32, // Whole months of pass time bought, whatever the tier's period length
     pub total_tokens_burned: u64,
+    pub tier_id: u8,
+    pub sponsor: Option<Pubkey>, // Wallet that paid for the latest purchase, if not the player
+    pub free_games_used: u8,
+    pub free_games_period: i64, // Monthly reset timestamp the free game counter belongs to
+    pub ticket_nonce: u64, // Next match ticket nonce
+    pub is_suspended: bool,
+    pub suspension_reason: u16, // Moderator-defined reason code
+    pub suspended_at: i64,
+    pub suspension_end: i64,
+    pub suspension_credit: i64, // Pass time credited at suspension to pause the pass clock
+    pub rent_payer: Pubkey, // Receives the rent back when the pass is closed
+    pub last_activity: i64, // Latest purchase, ticket or free game
+    pub pass_token_mint: Option<Pubkey>, // Soulbound Token-2022 mirror of this pass, if the player created one
 }
 
+pub const PLAYER_PASS_SPACE: usize =
+    8 + 1 + 32 + 8 + 8 + 1 + 4 + 8 + 1 + 33 + 1 + 8 + 8 + 1 + 2 + 8 + 8 + 8 + 32 + 8 + 33;
+
+#[account]
+pub struct MatchTicket {
+    pub bump: u8,
+    pub player: Pubkey,
+    pub mode: GameMode,
+    pub nonce: u64,
+    pub issued_at: i64,
+    pub expires_at: i64,
+    pub free_game: bool, // Issued against the free monthly quota
+    pub payer: Pubkey, // Receives the rent back when the ticket is consumed
+}
+
+// Permanent record of a reported game; its existence blocks replaying the same game_id
+#[account]
+pub struct Match {
+    pub bump: u8,
+    pub game_id: u64,
+    pub players: [Pubkey; MATCH_PLAYERS], // Seat order; partners sit across (0 & 2, 1 & 3)
+    pub mode: GameMode,
+    pub contract: ContractType,
+    pub declarers: u8, // Bit per seat that bid the contract
+    pub started_at: i64, // When the last seat's ticket was issued
+    pub ended_at: i64,
+    pub placements: [u8; MATCH_PLAYERS],
+    pub tricks: [u8; MATCH_PLAYERS],
+    pub log_hash: [u8; 32], // Hash of the off-chain game log
+    pub reporter: Pubkey,
+    pub rating_changes: [i32; MATCH_PLAYERS], // Applied to each seat's rating
+    pub status: MatchStatus,
+    pub attestations: u8, // Bit per seat that signed a player-proposed result
+    pub recorded_at: i64, // When the result was applied to the scores
+    pub disputed_by: Option<Pubkey>,
+    pub evidence_hash: [u8; 32], // Hash of the disputing player's off-chain evidence
+    pub season: u32, // Season whose monthly points and leaderboard the result counted towards
+    pub ticket_nonces: [u64; MATCH_PLAYERS], // Tickets a proposal holds until the table reaches the threshold
+    pub rejections: u8, // Bit per seat that rejected a player-proposed result
+    pub attestation_deadline: i64, // After this an unattested proposal can be closed by anyone
+    pub dispute_deadline: i64, // Fixed when the result is applied, so later window changes do not move it
+    pub snapshots: [ScoreSnapshot; MATCH_PLAYERS], // Each seat's score just before the result was applied
+}
+
+pub const MATCH_SPACE: usize = 8 + 1 + 8 + 32 * MATCH_PLAYERS + 1 + 1 + 1 + 8 + 8 + MATCH_PLAYERS + MATCH_PLAYERS
+    + 32 + 32 + 4 * MATCH_PLAYERS + 1 + 1 + 8 + 33 + 32 + 4 + 8 * MATCH_PLAYERS + 1 + 8 + 8 + 25 * MATCH_PLAYERS;
+
 #[account]
 pub struct PlayerScore {
     pub bump: u8,
@@ -97,9 +302,81 @@ pub struct PlayerScore {
     pub total_games_won: u32,
     pub current_rating: u32,
     pub highest_rating: u32,
-    pub monthly_rank: u32,
+    pub monthly_rank: u32, // Cached board position; only current when season == current_season and after a sync_monthly_rank
     pub last_game_time: i64,
     pub total_prizes_earned: u64,
+    pub rent_payer: Pubkey, // Receives the rent back when the score is closed
+    pub total_tricks_taken: u32,
+    // Whist statistics; fields are only ever appended so older accounts can be migrated in place
+    pub partnership_games: u32,
+    pub solo_games: u32,
+    pub abondance_games: u32,
+    pub misere_games: u32,
+    pub contracts_bid: u32,
+    pub contracts_made: u32,
+    pub current_win_streak: u32,
+    pub best_win_streak: u32,
+    pub partner_buckets: u32, // Bits set in partner_filter; a lower bound on distinct partners, who can share a bucket
+    pub partner_filter: [u8; PARTNER_FILTER_BYTES], // Bit per first byte of each partner's key
+    pub monthly_points: u32,
+    pub season: u32, // Season monthly_points and monthly_rank belong to; older values read as zero
+    pub decay_weeks_applied: u32, // Idle weeks already charged since last_game_time
+    pub unclaimed_prizes: u64, // Awarded by a DistributePrizes proposal and not yet claimed through distribute_prize
+    pub open_matches: u32, // Pending or disputed matches; the score cannot be closed while any remain
+}
+
+pub const PLAYER_SCORE_SPACE: usize =
+    8 + 1 + 32 + 4 + 4 + 4 + 4 + 4 + 8 + 8 + 32 + 4 + 4 + 4 + 4 + 4 + 4 + 4 + 4 + 4 + 4 + PARTNER_FILTER_BYTES + 4 + 4 + 4 + 8 + 4;
+
+// Ranked ladder for one season, best first
+#[account]
+pub struct Leaderboard {
+    pub bump: u8,
+    pub season: u32,
+    pub entries: Vec<LeaderboardEntry>, // At most LEADERBOARD_SIZE, sorted by leaderboard_order
+}
+
+pub const LEADERBOARD_SPACE: usize = 8 + 1 + 4 + 4 + (32 + 4 + 4) * LEADERBOARD_SIZE;
+
+// Record of a closed season, written by monthly_reset; its standings follow any late void of one of its matches
+#[account]
+pub struct Season {
+    pub bump: u8,
+    pub season: u32,
+    pub started_at: i64,
+    pub ended_at: i64,
+    pub standings: Vec<LeaderboardEntry>, // Leaderboard at close, best first, corrected by resolve_dispute
+    pub total_burned: u64, // WZN spent on passes during the season
+    pub prize_pool: u64, // Prize vault balance when the season closed
+}
+
+// What a voided match has to put back that cannot be recomputed from its deltas
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default)]
+pub struct ScoreSnapshot {
+    pub highest_rating: u32,
+    pub current_win_streak: u32,
+    pub best_win_streak: u32,
+    pub last_game_time: i64,
+    pub decay_weeks_applied: u32,
+    pub new_partner: bool, // The match set a partner_filter bit that was not set before
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,
+    pub rating: u32,
+    pub monthly_points: u32,
+}
+
+// Everything a table reports about a finished game
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MatchResult {
+    pub mode: GameMode,
+    pub contract: ContractType,
+    pub declarers: u8, // Bit per seat that bid the contract
+    pub placements: [u8; MATCH_PLAYERS],
+    pub tricks: [u8; MATCH_PLAYERS],
+    pub log_hash: [u8; 32], // Hash of the off-chain game log
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -117,13 +394,86 @@ pub struct Proposal {
     pub executed_at: Option<i64>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct BurnRoute {
+    pub destination: VaultDestination,
+    pub bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum VaultDestination {
+    BurnVault,
+    RecoveryVault,
+    PrizeVault,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct PriceStep {
+    pub effective_at: i64,
+    pub cost: u64, // Monthly pass cost in WZN from effective_at onwards
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum PricingMode {
+    Fixed,
+    Pegged,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum FeeMode {
+    UserPays,
+    ProjectSponsored,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum GameMode {
+    Casual,
+    Ranked,
+    Tournament,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum ContractType {
+    Partnership, // Two declarers play together against the other two
+    Solo,
+    Abondance,
+    Misere, // Declarers must take no tricks; several players may bid it at once
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum MatchStatus {
+    AwaitingAttestation, // Proposed by a seat, waiting until the table reaches the threshold
+    Pending, // Applied to the scores, open to dispute until the window closes
+    Disputed,
+    Final,
+    Voided, // Rolled back by an arbiter
+    Contested, // Rejected past the threshold before it was applied; an arbiter records or voids it
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
 pub enum ProposalType {
     UnlockBurnVault,
-    DistributePrizes,
+    DistributePrizes { recipient: Pubkey }, // amount is credited to the recipient's unclaimed prizes
     UpdateMonthlyPassCost,
     EmergencyUnlock,
     UpdateDAO,
+    UpdateMaxPrepaidMonths,
+    UpdateFeeMode,
+    UpdateFreeGamesPerPeriod,
+    UpdateBurnBps,
+    UpdateBurnRoutes { routes: [BurnRoute; MAX_BURN_ROUTES] }, // Zero-bps legs are unused slots
+    SchedulePassCost { effective_at: i64 }, // amount is the new cost; 0 cancels the step
+    UpdateInactivityCloseMonths,
+    UpdateAttestationThreshold,
+    UpdateDisputeWindow,
+    UpdateProvisionalGames,
+    UpdateRatingDecayWeeks,
+    UpdateRatingFloor,
+    AddFeeSponsor { sponsor: Pubkey },
+    RemoveFeeSponsor { sponsor: Pubkey },
+    WithdrawRecoveryVault { recipient: Pubkey }, // amount is taken from the recovery vault
+    SuspendPlayer { player: Pubkey, reason_code: u16, suspension_end: i64 },
+    ReinstatePlayer { player: Pubkey },
 }
 
 // Helper functions for PDA derivation
@@ -139,6 +489,79 @@ pub fn get_prize_vault_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[PRIZE_VAULT_SEED], &crate::ID)
 }
 
+// Signer seeds for CPIs where a vault PDA is the token account authority
+pub fn burn_vault_signer_seeds(bump: &u8) -> [&[u8]; 2] {
+    [BURN_VAULT_SEED, std::slice::from_ref(bump)]
+}
+
+pub fn prize_vault_signer_seeds(bump: &u8) -> [&[u8]; 2] {
+    [PRIZE_VAULT_SEED, std::slice::from_ref(bump)]
+}
+
+pub fn recovery_vault_signer_seeds(bump: &u8) -> [&[u8]; 2] {
+    [RECOVERY_VAULT_SEED, std::slice::from_ref(bump)]
+}
+
+pub fn get_burn_vault_token_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BURN_VAULT_TOKEN_SEED], &crate::ID)
+}
+
+pub fn get_prize_vault_token_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRIZE_VAULT_TOKEN_SEED], &crate::ID)
+}
+
+pub fn get_recovery_vault_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECOVERY_VAULT_SEED], &crate::ID)
+}
+
+pub fn get_recovery_vault_token_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECOVERY_VAULT_TOKEN_SEED], &crate::ID)
+}
+
+pub fn get_pass_tier_pda(tier_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PASS_TIER_SEED, &[tier_id]], &crate::ID)
+}
+
+pub fn get_fee_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_CONFIG_SEED], &crate::ID)
+}
+
+pub fn get_match_ticket_pda(player: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MATCH_TICKET_SEED, player.as_ref(), &nonce.to_le_bytes()], &crate::ID)
+}
+
+pub fn get_match_pda(game_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MATCH_SEED, &game_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn get_leaderboard_pda(season: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LEADERBOARD_SEED, &season.to_le_bytes()], &crate::ID)
+}
+
+pub fn get_season_pda(season: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEASON_SEED, &season.to_le_bytes()], &crate::ID)
+}
+
+pub fn get_role_registry_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROLE_REGISTRY_SEED], &crate::ID)
+}
+
+pub fn get_price_feed_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRICE_FEED_SEED], &crate::ID)
+}
+
+pub fn get_pass_mint_pda(player: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PASS_MINT_SEED, player.as_ref()], &crate::ID)
+}
+
+pub fn get_pass_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PASS_AUTHORITY_SEED], &crate::ID)
+}
+
+pub fn pass_authority_signer_seeds(bump: &u8) -> [&[u8]; 2] {
+    [PASS_AUTHORITY_SEED, std::slice::from_ref(bump)]
+}
+
 pub fn get_dao_governance_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[DAO_GOVERNANCE_SEED], &crate::ID)
 }
@@ -157,7 +580,7 @@ pub fn get_player_score_pda(player: &Pubkey) -> (Pubkey, u8) {
 
 // Helper functions for validation
 pub fn validate_token_account(
-    account: &Account<TokenAccount>,
+    account: &InterfaceAccount<TokenAccount>,
     expected_mint: &Pubkey,
     expected_owner: &Pubkey,
 ) -> Result<()> {
@@ -171,6 +594,373 @@ pub fn is_pass_active(pass: &PlayerPass) -> bool {
     pass.is_active && now >= pass.pass_start_time && now <= pass.pass_end_time
 }
 
+pub fn pass_extension_base(pass: &PlayerPass, now: i64) -> i64 {
+    // Renewals stack on the remaining time of a still-running pass
+    if pass.is_active && pass.pass_end_time > now {
+        pass.pass_end_time
+    } else {
+        now
+    }
+}
+
+pub fn calculate_tier_cost(monthly_pass_cost: u64, cost_bps: u32) -> Option<u64> {
+    let cost = (monthly_pass_cost as u128)
+        .checked_mul(cost_bps as u128)?
+        .checked_div(BPS_DENOMINATOR as u128)?;
+    u64::try_from(cost).ok()
+}
+
+// Splits a purchase into the amount destroyed and the amount locked in the burn vault
+pub fn split_burn_amount(amount: u64, burn_bps: u16) -> Option<(u64, u64)> {
+    let destroyed = (amount as u128)
+        .checked_mul(burn_bps as u128)?
+        .checked_div(BPS_DENOMINATOR as u128)?;
+    let destroyed = u64::try_from(destroyed).ok()?;
+    Some((destroyed, amount.checked_sub(destroyed)?))
+}
+
+pub fn default_burn_routes() -> Vec<BurnRoute> {
+    vec![BurnRoute { destination: VaultDestination::BurnVault, bps: BPS_DENOMINATOR as u16 }]
+}
+
+// Drops the unused (zero-bps) slots of a proposed table and checks that every destination
+// appears at most once and the legs sum to 10000 bps
+pub fn validated_burn_routes(routes: &[BurnRoute]) -> Option<Vec<BurnRoute>> {
+    let legs: Vec<BurnRoute> = routes.iter().copied().filter(|route| route.bps > 0).collect();
+    let total_bps: u64 = legs.iter().map(|route| route.bps as u64).sum();
+    let distinct = legs
+        .iter()
+        .enumerate()
+        .all(|(index, route)| legs[..index].iter().all(|other| other.destination != route.destination));
+
+    (total_bps == BPS_DENOMINATOR && distinct).then_some(legs)
+}
+
+// Splits an amount across the routing table. The last leg absorbs rounding so the legs sum exactly.
+pub fn split_by_routes(amount: u64, routes: &[BurnRoute]) -> Option<Vec<(VaultDestination, u64)>> {
+    let mut legs = Vec::with_capacity(routes.len());
+    let mut remaining = amount;
+
+    for (index, route) in routes.iter().enumerate() {
+        let leg = if index + 1 == routes.len() {
+            remaining
+        } else {
+            let share = (amount as u128)
+                .checked_mul(route.bps as u128)?
+                .checked_div(BPS_DENOMINATOR as u128)?;
+            u64::try_from(share).ok()?
+        };
+        remaining = remaining.checked_sub(leg)?;
+        legs.push((route.destination, leg));
+    }
+
+    Some(legs)
+}
+
+// Monthly pass cost in effect at `now`: the latest step that has started, else the base cost
+pub fn scheduled_pass_cost(game_state: &GameState, now: i64) -> u64 {
+    game_state
+        .price_schedule
+        .iter()
+        .rev()
+        .find(|step| step.effective_at <= now)
+        .map_or(game_state.monthly_pass_cost, |step| step.cost)
+}
+
+// Moves steps that have taken effect into monthly_pass_cost so only future steps stay listed
+pub fn fold_elapsed_price_steps(game_state: &mut GameState, now: i64) {
+    game_state.monthly_pass_cost = scheduled_pass_cost(game_state, now);
+    game_state.price_schedule.retain(|step| step.effective_at > now);
+}
+
+// Converts a reference currency amount into WZN base units at price * 10^expo reference units per WZN
+pub fn reference_to_wzn(reference_amount: u64, price: u64, expo: i32, decimals: u8) -> Option<u64> {
+    if price == 0 {
+        return None;
+    }
+    let scale = REFERENCE_PRICE_DECIMALS.checked_add(expo)?;
+    let numerator = (reference_amount as u128).checked_mul(10u128.checked_pow(decimals as u32)?)?;
+    let cost = if scale >= 0 {
+        numerator.checked_div((price as u128).checked_mul(10u128.checked_pow(scale as u32)?)?)?
+    } else {
+        numerator
+            .checked_mul(10u128.checked_pow(scale.unsigned_abs())?)?
+            .checked_div(price as u128)?
+    };
+    u64::try_from(cost).ok()
+}
+
+// Pegged monthly cost from the feed, or None if the price is stale, too uncertain or unusable
+pub fn pegged_pass_cost(game_state: &GameState, feed: &PriceFeed, decimals: u8, now: i64) -> Option<u64> {
+    if feed.price == 0 || now - feed.publish_time > game_state.max_price_age {
+        return None;
+    }
+    let confidence_bps = (feed.confidence as u128)
+        .checked_mul(BPS_DENOMINATOR as u128)?
+        .checked_div(feed.price as u128)?;
+    if confidence_bps > game_state.max_price_confidence_bps as u128 {
+        return None;
+    }
+    let cost = reference_to_wzn(game_state.reference_pass_cost, feed.price, feed.expo, decimals)?;
+    Some(cost.clamp(game_state.min_pass_cost, game_state.max_pass_cost))
+}
+
+pub fn tier_allows_mode(tier: &PassTier, mode: GameMode) -> bool {
+    match mode {
+        GameMode::Casual => true,
+        GameMode::Ranked => tier.ranked_access,
+        GameMode::Tournament => tier.tournament_entry,
+    }
+}
+
+pub fn is_valid_fee_payer(fee_config: &FeeConfig, fee_payer: &Pubkey, player: &Pubkey) -> bool {
+    match fee_config.fee_mode {
+        FeeMode::UserPays => fee_payer == player,
+        FeeMode::ProjectSponsored => fee_config.approved_sponsors.contains(fee_payer),
+    }
+}
+
+pub fn fee_mode_from_u64(value: u64) -> Option<FeeMode> {
+    match value {
+        0 => Some(FeeMode::UserPays),
+        1 => Some(FeeMode::ProjectSponsored),
+        _ => None,
+    }
+}
+
+pub fn free_games_remaining(pass: &PlayerPass, game_state: &GameState) -> u8 {
+    // The counter starts over once a newer monthly reset has happened
+    if pass.free_games_period < game_state.last_monthly_reset {
+        game_state.free_games_per_period
+    } else {
+        game_state.free_games_per_period.saturating_sub(pass.free_games_used)
+    }
+}
+
+pub fn is_pass_suspended(pass: &PlayerPass, now: i64) -> bool {
+    pass.is_suspended && now < pass.suspension_end
+}
+
+// A pass can be closed once it has no running time, no live suspension, no free games counted
+// this period and no ticket that could still be reported
+pub fn can_close_player_pass(pass: &PlayerPass, game_state: &GameState, now: i64) -> bool {
+    let has_pass = pass.is_active && now <= pass.pass_end_time;
+    !has_pass
+        && !is_pass_suspended(pass, now)
+        && pass.free_games_period < game_state.last_monthly_reset
+        && now - pass.last_activity > MATCH_TICKET_VALIDITY
+}
+
+// Metadata values are fixed width so updates never change the mint's size
+pub fn pass_tier_field_value(tier_id: u8) -> String {
+    format!("{:03}", tier_id)
+}
+
+pub fn pass_expiry_field_value(pass_end_time: i64) -> String {
+    format!("{:012}", pass_end_time.max(0))
+}
+
+// Bytes the token metadata extension takes up in the pass mint, including its TLV header
+pub fn pass_token_metadata_len() -> usize {
+    let fields = [
+        (PASS_TIER_FIELD, pass_tier_field_value(0)),
+        (PASS_EXPIRY_FIELD, pass_expiry_field_value(0)),
+    ];
+    4 + 32 + 32
+        + 4 + PASS_TOKEN_NAME.len()
+        + 4 + PASS_TOKEN_SYMBOL.len()
+        + 4 + PASS_TOKEN_URI.len()
+        + 4 + fields.iter().map(|(key, value)| 4 + key.len() + 4 + value.len()).sum::<usize>()
+}
+
+pub fn is_inactive_for_close(last_activity: i64, game_state: &GameState, now: i64) -> bool {
+    now - last_activity >= game_state.inactivity_close_months as i64 * MONTHLY_SECONDS
+}
+
+// The game authority always acts as a moderator, so passes can be moderated before any moderator is registered
+pub fn is_moderator(role_registry: &RoleRegistry, game_state: &GameState, signer: &Pubkey) -> bool {
+    *signer == game_state.authority || role_registry.moderators.contains(signer)
+}
+
+// The game authority can always arbitrate, so disputes can be resolved before any arbiter is registered
+pub fn is_arbiter(role_registry: &RoleRegistry, game_state: &GameState, signer: &Pubkey) -> bool {
+    *signer == game_state.authority || role_registry.arbiters.contains(signer)
+}
+
+// Every seat that has not rejected a proposal may still attest it
+pub fn can_reach_attestation_threshold(match_record: &Match, threshold: u8) -> bool {
+    MATCH_PLAYERS as u32 - match_record.rejections.count_ones() >= threshold as u32
+}
+
+pub fn is_dispute_window_open(match_record: &Match, now: i64) -> bool {
+    now <= match_record.dispute_deadline
+}
+
+pub fn is_game_server(role_registry: &RoleRegistry, signer: &Pubkey) -> bool {
+    role_registry.game_servers.contains(signer)
+}
+
+pub fn has_distinct_players(players: &[Pubkey; MATCH_PLAYERS]) -> bool {
+    (0..MATCH_PLAYERS).all(|i| ((i + 1)..MATCH_PLAYERS).all(|j| players[i] != players[j]))
+}
+
+// A strict majority of the table, so two colluding players can never forge a result
+pub fn is_valid_attestation_threshold(threshold: u64) -> bool {
+    threshold as usize > MATCH_PLAYERS / 2 && threshold as usize <= MATCH_PLAYERS
+}
+
+// Placements run from 1 (won) to MATCH_PLAYERS; somebody has to win and somebody has to lose
+pub fn is_valid_placements(placements: &[u8; MATCH_PLAYERS]) -> bool {
+    placements.iter().all(|&p| p >= 1 && p as usize <= MATCH_PLAYERS)
+        && placements.contains(&1)
+        && placements.iter().any(|&p| p != 1)
+}
+
+// Declarer count fits the contract and, except for misère, all declarers share one outcome
+pub fn is_valid_match_result(result: &MatchResult) -> bool {
+    let declarer_count = result.declarers.count_ones() as usize;
+    let seats_valid = result.declarers != 0 && (result.declarers as usize) < (1 << MATCH_PLAYERS);
+    let count_valid = match result.contract {
+        ContractType::Partnership => declarer_count == 2,
+        ContractType::Solo | ContractType::Abondance => declarer_count == 1,
+        ContractType::Misere => declarer_count >= 1,
+    };
+    // Declarers share one outcome: either all of them or none of them are among the winners
+    let winners = (0..MATCH_PLAYERS)
+        .filter(|&seat| result.placements[seat] == 1)
+        .fold(0u8, |mask, seat| mask | 1 << seat);
+    let winning_declarers = winners & result.declarers;
+    let outcome_valid = result.contract == ContractType::Misere
+        || winning_declarers == 0
+        || winning_declarers == result.declarers;
+
+    is_valid_placements(&result.placements) && seats_valid && count_valid && outcome_valid
+}
+
+// The only other seat on the same side, when the table split two against two
+pub fn match_partner(players: &[Pubkey; MATCH_PLAYERS], won: &[bool; MATCH_PLAYERS], seat: usize) -> Option<Pubkey> {
+    if won.iter().filter(|&&w| w).count() * 2 != MATCH_PLAYERS {
+        return None;
+    }
+    (0..MATCH_PLAYERS)
+        .find(|&other| other != seat && won[other] == won[seat])
+        .map(|other| players[other])
+}
+
+pub fn record_partner(player_score: &mut PlayerScore, partner: &Pubkey) {
+    let bit = partner.to_bytes()[0] as usize;
+    player_score.partner_filter[bit / 8] |= 1 << (bit % 8);
+    player_score.partner_buckets = player_score.partner_filter.iter().map(|byte| byte.count_ones()).sum();
+}
+
+pub fn forget_partner(player_score: &mut PlayerScore, partner: &Pubkey) {
+    let bit = partner.to_bytes()[0] as usize;
+    player_score.partner_filter[bit / 8] &= !(1 << (bit % 8));
+    player_score.partner_buckets = player_score.partner_filter.iter().map(|byte| byte.count_ones()).sum();
+}
+
+pub fn match_points(won: bool) -> u32 {
+    if won {
+        MATCH_WIN_POINTS
+    } else {
+        MATCH_PLAY_POINTS
+    }
+}
+
+// Starts a new season's monthly counters the first time a score is touched in it
+pub fn roll_score_season(player_score: &mut PlayerScore, season: u32) {
+    if player_score.season != season {
+        player_score.season = season;
+        player_score.monthly_points = 0;
+        player_score.monthly_rank = 0;
+    }
+}
+
+// Sets the fields appended since the original layout on a score grown in place; the original account had no
+// rent payer, so the player is the one refunded when it is closed
+pub fn init_migrated_player_score(player_score: &mut PlayerScore) {
+    player_score.rent_payer = player_score.player;
+    player_score.total_tricks_taken = 0;
+    player_score.partnership_games = 0;
+    player_score.solo_games = 0;
+    player_score.abondance_games = 0;
+    player_score.misere_games = 0;
+    player_score.contracts_bid = 0;
+    player_score.contracts_made = 0;
+    player_score.current_win_streak = 0;
+    player_score.best_win_streak = 0;
+    player_score.partner_buckets = 0;
+    player_score.partner_filter = [0; PARTNER_FILTER_BYTES];
+    player_score.monthly_points = 0;
+    player_score.season = 0;
+    player_score.decay_weeks_applied = 0;
+    player_score.unclaimed_prizes = 0;
+    player_score.open_matches = 0;
+}
+
+pub fn is_provisional(player_score: &PlayerScore, game_state: &GameState) -> bool {
+    player_score.total_games_played < game_state.provisional_games
+}
+
+// Charges any idle weeks not yet applied, so decay is settled lazily whenever a score is touched
+pub fn apply_rating_decay(player_score: &mut PlayerScore, game_state: &GameState, now: i64) {
+    if player_score.last_game_time == 0 {
+        return;
+    }
+    let weeks = crate::rating::decay_weeks(now - player_score.last_game_time, game_state.rating_decay_weeks);
+    if weeks > player_score.decay_weeks_applied {
+        let pending = weeks - player_score.decay_weeks_applied;
+        player_score.current_rating =
+            crate::rating::decay_rating(player_score.current_rating, game_state.rating_floor, pending);
+        player_score.decay_weeks_applied = weeks;
+    }
+}
+
+// More monthly points first, then higher rating, then the lower player key so ties never depend on insertion order
+pub fn leaderboard_order(a: &LeaderboardEntry, b: &LeaderboardEntry) -> std::cmp::Ordering {
+    b.monthly_points
+        .cmp(&a.monthly_points)
+        .then(b.rating.cmp(&a.rating))
+        .then(a.player.cmp(&b.player))
+}
+
+// Moves the player's entry to its sorted position, dropping whoever falls off the bottom
+pub fn upsert_leaderboard_entry(entries: &mut Vec<LeaderboardEntry>, entry: LeaderboardEntry) {
+    entries.retain(|existing| existing.player != entry.player);
+    let position = entries
+        .binary_search_by(|existing| leaderboard_order(existing, &entry))
+        .unwrap_or_else(|position| position);
+    if position < LEADERBOARD_SIZE {
+        entries.insert(position, entry);
+        entries.truncate(LEADERBOARD_SIZE);
+    }
+}
+
+// 1-based position on the board, 0 when the player is not on it
+pub fn leaderboard_rank(entries: &[LeaderboardEntry], player: &Pubkey) -> u32 {
+    entries
+        .iter()
+        .position(|entry| entry.player == *player)
+        .map_or(0, |position| position as u32 + 1)
+}
+
+pub fn leaderboard_entry(player_score: &PlayerScore) -> LeaderboardEntry {
+    LeaderboardEntry {
+        player: player_score.player,
+        rating: player_score.current_rating,
+        monthly_points: player_score.monthly_points,
+    }
+}
+
+// Provisional players stay off the public ladder until their rating has settled
+pub fn refresh_leaderboard_entry(entries: &mut Vec<LeaderboardEntry>, player_score: &PlayerScore, game_state: &GameState) {
+    if is_provisional(player_score, game_state) {
+        entries.retain(|existing| existing.player != player_score.player);
+    } else {
+        upsert_leaderboard_entry(entries, leaderboard_entry(player_score));
+    }
+}
+
 pub fn is_monthly_reset_needed(last_reset: i64) -> bool {
     let now = Clock::get().unwrap().unix_timestamp;
     now - last_reset >= MONTHLY_SECONDS
@@ -188,6 +978,57 @@ pub fn can_emergency_unlock(burn_vault: &BurnVault) -> bool {
     now - burn_vault.last_dao_unlock >= EMERGENCY_UNLOCK_DELAY
 }
 
+// Same quorum rule as DAO votes, rounded up so a quorum is never met by a single member of a small group
+pub fn emergency_approvals_required(emergency_recovery: &EmergencyRecovery) -> usize {
+    let required = (emergency_recovery.total_members as u64 * emergency_recovery.quorum_percentage).div_ceil(100);
+    required.max(1) as usize
+}
+
+pub fn is_emergency_unlock_pending(emergency_recovery: &EmergencyRecovery, now: i64) -> bool {
+    emergency_recovery.pending_unlock_amount > 0
+        && now <= emergency_recovery.pending_unlock_requested_at + EMERGENCY_APPROVAL_WINDOW
+}
+
 pub fn calculate_emergency_unlock_amount(total_locked: u64, percentage: u64) -> u64 {
     (total_locked * percentage) / 100
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // The original layout ends at total_prizes_earned; realloc zero-fills everything after it
+    fn legacy_score_data(player: &Pubkey, rating: u32) -> Vec<u8> {
+        let mut data = PlayerScore::DISCRIMINATOR.to_vec();
+        data.push(254);
+        data.extend_from_slice(player.as_ref());
+        for value in [12u32, 7, rating, rating + 40, 3] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&500u64.to_le_bytes());
+        data.resize(PLAYER_SCORE_SPACE, 0);
+        data
+    }
+
+    #[test]
+    fn migrated_score_can_be_closed_by_its_player() {
+        let player = Pubkey::new_unique();
+        let mut data = legacy_score_data(&player, 1040);
+
+        let mut player_score = PlayerScore::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(player_score.rent_payer, Pubkey::default());
+
+        init_migrated_player_score(&mut player_score);
+        player_score.try_serialize(&mut &mut data[..]).unwrap();
+
+        // The close instructions refund rent_payer and refuse scores with open matches or unclaimed prizes
+        let migrated = PlayerScore::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(migrated.rent_payer, player);
+        assert_eq!(migrated.open_matches, 0);
+        assert_eq!(migrated.unclaimed_prizes, 0);
+        assert_eq!(migrated.current_rating, 1040);
+        assert_eq!(migrated.total_games_played, 12);
+        assert_eq!(migrated.total_prizes_earned, 500);
+    }
+}
@@ -2,7 +2,17 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { WznBurnPlay } from "../target/types/wzn_burn_play";
 import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createMint,
+  createAccount,
+  mintTo,
+  getAccount,
+  getMint,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import { assert } from "chai";
 
 describe("wzn-burn-play", () => {
@@ -14,6 +24,9 @@ describe("wzn-burn-play", () => {
   // Test accounts
   const authority = Keypair.generate();
   const player = Keypair.generate();
+  const giftRecipient = Keypair.generate();
+  const freePlayer = Keypair.generate();
+  const moderator = Keypair.generate();
   const daoMember1 = Keypair.generate();
   const daoMember2 = Keypair.generate();
   const emergencyMember1 = Keypair.generate();
@@ -25,15 +38,94 @@ describe("wzn-burn-play", () => {
   let authorityTokenAccount: PublicKey;
   let burnVaultTokenAccount: PublicKey;
   let prizeVaultTokenAccount: PublicKey;
+  let recoveryVaultTokenAccount: PublicKey;
 
   // PDAs
   let gameStatePda: PublicKey;
   let burnVaultPda: PublicKey;
   let prizeVaultPda: PublicKey;
+  let recoveryVaultPda: PublicKey;
   let daoGovernancePda: PublicKey;
   let emergencyRecoveryPda: PublicKey;
   let playerPassPda: PublicKey;
   let playerScorePda: PublicKey;
+  let feeConfigPda: PublicKey;
+  let roleRegistryPda: PublicKey;
+  let priceFeedPda: PublicKey;
+  let casualTierPda: PublicKey;
+  let rankedTierPda: PublicKey;
+  let championshipTierPda: PublicKey;
+
+  const matchTicketPda = (owner: PublicKey, nonce: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("match_ticket"), owner.toBuffer(), new anchor.BN(nonce).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const playerScorePdaFor = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("player_score"), owner.toBuffer()], program.programId)[0];
+
+  const leaderboardPda = (season: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), new anchor.BN(season).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    )[0];
+
+  const seasonPda = (season: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("season"), new anchor.BN(season).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    )[0];
+
+  const matchPda = (gameId: anchor.BN) =>
+    PublicKey.findProgramAddressSync([Buffer.from("match"), gameId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+
+  // Funds a fresh wallet and seats it at a casual table on its free monthly games (ticket nonce 0)
+  const seatFreePlayer = async (seat: Keypair) => {
+    const sig = await provider.connection.requestAirdrop(seat.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig);
+
+    const [seatPassPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player_pass"), seat.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .checkGameAccess({ casual: {} })
+      .accounts({
+        gameState: gameStatePda,
+        feeConfig: feeConfigPda,
+        playerPass: seatPassPda,
+        passTier: null,
+        matchTicket: matchTicketPda(seat.publicKey, 0),
+        player: seat.publicKey,
+        feePayer: seat.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([seat])
+      .rpc();
+  };
+
+  // The pass holder joins a casual table with its next ticket
+  const seatPassHolder = (nonce: number) =>
+    program.methods
+      .checkGameAccess({ casual: {} })
+      .accounts({
+        gameState: gameStatePda,
+        feeConfig: feeConfigPda,
+        playerPass: playerPassPda,
+        passTier: rankedTierPda,
+        matchTicket: matchTicketPda(player.publicKey, nonce),
+        player: player.publicKey,
+        feePayer: player.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player])
+      .rpc();
+
+  const CASUAL_TIER = 0;
+  const RANKED_TIER = 1;
+  const CHAMPIONSHIP_TIER = 2;
 
   before(async () => {
     // Airdrop SOL to test accounts
@@ -41,6 +133,7 @@ describe("wzn-burn-play", () => {
     await provider.connection.requestAirdrop(player.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
     await provider.connection.requestAirdrop(daoMember1.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
     await provider.connection.requestAirdrop(daoMember2.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.requestAirdrop(freePlayer.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
 
     // Create WZN token mint
     wznMint = await createMint(
@@ -101,6 +194,11 @@ describe("wzn-burn-play", () => {
       program.programId
     );
 
+    [recoveryVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("recovery_vault")],
+      program.programId
+    );
+
     [daoGovernancePda] = PublicKey.findProgramAddressSync(
       [Buffer.from("dao_governance")],
       program.programId
@@ -121,22 +219,58 @@ describe("wzn-burn-play", () => {
       program.programId
     );
 
+    [feeConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_config")],
+      program.programId
+    );
+
+    [roleRegistryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("role_registry")],
+      program.programId
+    );
+
+    [priceFeedPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("price_feed")],
+      program.programId
+    );
+
+    [casualTierPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pass_tier"), Buffer.from([CASUAL_TIER])],
+      program.programId
+    );
+
+    [rankedTierPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pass_tier"), Buffer.from([RANKED_TIER])],
+      program.programId
+    );
+
+    [championshipTierPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pass_tier"), Buffer.from([CHAMPIONSHIP_TIER])],
+      program.programId
+    );
+
     [burnVaultTokenAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from("burn_vault")],
+      [Buffer.from("burn_vault_token")],
       program.programId
     );
 
     [prizeVaultTokenAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from("prize_vault")],
+      [Buffer.from("prize_vault_token")],
+      program.programId
+    );
+
+    [recoveryVaultTokenAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("recovery_vault_token")],
       program.programId
     );
   });
 
   it("Initializes the game", async () => {
     const monthlyPassCost = 10000000; // 10 WZN
+    const maxPrepaidMonths = 12;
 
     await program.methods
-      .initializeGame(new anchor.BN(monthlyPassCost))
+      .initializeGame(new anchor.BN(monthlyPassCost), maxPrepaidMonths)
       .accounts({
         gameState: gameStatePda,
         authority: authority.publicKey,
@@ -151,6 +285,64 @@ describe("wzn-burn-play", () => {
     assert.equal(gameState.authority.toString(), authority.publicKey.toString());
     assert.equal(gameState.monthlyPassCost.toNumber(), monthlyPassCost);
     assert.equal(gameState.isInitialized, true);
+    assert.equal(gameState.maxPrepaidMonths, maxPrepaidMonths);
+    assert.equal(gameState.freeGamesPerPeriod, 2);
+    assert.equal(gameState.tokenProgram.toString(), TOKEN_PROGRAM_ID.toString());
+  });
+
+  it("Initializes the fee config", async () => {
+    await program.methods
+      .initializeFeeConfig({ userPays: {} }, [authority.publicKey])
+      .accounts({
+        feeConfig: feeConfigPda,
+        gameState: gameStatePda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const feeConfig = await program.account.feeConfig.fetch(feeConfigPda);
+    assert.deepEqual(feeConfig.feeMode, { userPays: {} });
+    assert.equal(feeConfig.approvedSponsors.length, 1);
+  });
+
+  it("Configures pass tiers", async () => {
+    const monthSeconds = 30 * 24 * 60 * 60;
+    const tiers = [
+      // id, pda, cost bps, duration, ranked, tournament, prizes
+      [CASUAL_TIER, casualTierPda, 5000, monthSeconds, false, false, false],
+      [RANKED_TIER, rankedTierPda, 10000, monthSeconds, true, false, true],
+      [CHAMPIONSHIP_TIER, championshipTierPda, 100000, 12 * monthSeconds, true, true, true],
+    ] as const;
+
+    for (const [tierId, tierPda, costBps, duration, ranked, tournament, prizes] of tiers) {
+      await program.methods
+        .configurePassTier(tierId, {
+          costBps,
+          duration: new anchor.BN(duration),
+          rankedAccess: ranked,
+          tournamentEntry: tournament,
+          prizeEligible: prizes,
+          isActive: true,
+        })
+        .accounts({
+          gameState: gameStatePda,
+          passTier: tierPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    const rankedTier = await program.account.passTier.fetch(rankedTierPda);
+    assert.equal(rankedTier.costBps, 10000);
+    assert.equal(rankedTier.rankedAccess, true);
+    assert.equal(rankedTier.tournamentEntry, false);
+
+    const championshipTier = await program.account.passTier.fetch(championshipTierPda);
+    assert.equal(championshipTier.duration.toNumber(), 12 * monthSeconds);
   });
 
   it("Initializes the burn vault", async () => {
@@ -161,7 +353,11 @@ describe("wzn-burn-play", () => {
       .initializeBurnVault(new anchor.BN(emergencyThreshold), new anchor.BN(minimumBalance))
       .accounts({
         burnVault: burnVaultPda,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        gameState: gameStatePda,
         authority: authority.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       })
@@ -172,6 +368,11 @@ describe("wzn-burn-play", () => {
     assert.equal(burnVault.isInitialized, true);
     assert.equal(burnVault.totalLocked.toNumber(), 0);
     assert.equal(burnVault.emergencyUnlockThreshold.toNumber(), emergencyThreshold);
+    assert.equal(burnVault.tokenAccount.toString(), burnVaultTokenAccount.toString());
+
+    const vaultTokenAccount = await getAccount(provider.connection, burnVaultTokenAccount);
+    assert.equal(vaultTokenAccount.mint.toString(), wznMint.toString());
+    assert.equal(vaultTokenAccount.owner.toString(), burnVaultPda.toString());
   });
 
   it("Initializes the prize vault", async () => {
@@ -179,7 +380,11 @@ describe("wzn-burn-play", () => {
       .initializePrizeVault()
       .accounts({
         prizeVault: prizeVaultPda,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        gameState: gameStatePda,
         authority: authority.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       })
@@ -189,6 +394,38 @@ describe("wzn-burn-play", () => {
     const prizeVault = await program.account.prizeVault.fetch(prizeVaultPda);
     assert.equal(prizeVault.isInitialized, true);
     assert.equal(prizeVault.totalDeposited.toNumber(), 0);
+    assert.equal(prizeVault.tokenAccount.toString(), prizeVaultTokenAccount.toString());
+
+    const vaultTokenAccount = await getAccount(provider.connection, prizeVaultTokenAccount);
+    assert.equal(vaultTokenAccount.mint.toString(), wznMint.toString());
+    assert.equal(vaultTokenAccount.owner.toString(), prizeVaultPda.toString());
+  });
+
+  it("Initializes the recovery vault", async () => {
+    await program.methods
+      .initializeRecoveryVault()
+      .accounts({
+        recoveryVault: recoveryVaultPda,
+        recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+        gameState: gameStatePda,
+        authority: authority.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority])
+      .rpc();
+
+    const recoveryVault = await program.account.recoveryVault.fetch(recoveryVaultPda);
+    assert.equal(recoveryVault.isInitialized, true);
+    assert.equal(recoveryVault.tokenAccount.toString(), recoveryVaultTokenAccount.toString());
+
+    // Everything starts out routed to the burn vault
+    const gameState = await program.account.gameState.fetch(gameStatePda);
+    assert.equal(gameState.burnRoutes.length, 1);
+    assert.deepEqual(gameState.burnRoutes[0].destination, { burnVault: {} });
+    assert.equal(gameState.burnRoutes[0].bps, 10000);
   });
 
   it("Initializes DAO governance", async () => {
@@ -231,20 +468,70 @@ describe("wzn-burn-play", () => {
     assert.equal(emergencyRecovery.backupMembers.length, 2);
   });
 
+  it("Collects backup member approvals before an emergency unlock", async () => {
+    const unlockAmount = new anchor.BN(1000000);
+    const approve = (member: Keypair, amount: anchor.BN) =>
+      program.methods
+        .approveEmergencyUnlock(amount, emergencyMember1.publicKey)
+        .accounts({
+          emergencyRecovery: emergencyRecoveryPda,
+          member: member.publicKey,
+        })
+        .signers([member])
+        .rpc();
+
+    try {
+      await approve(player, unlockAmount);
+      assert.fail("Expected a non-member approval to fail");
+    } catch (err) {
+      assert.include(err.toString(), "EmergencyMemberNotFound");
+    }
+
+    await approve(emergencyMember1, unlockAmount);
+
+    // A second member cannot swap in a different unlock while this one is pending
+    try {
+      await approve(emergencyMember2, unlockAmount.muln(2));
+      assert.fail("Expected a competing request to fail");
+    } catch (err) {
+      assert.include(err.toString(), "EmergencyUnlockPending");
+    }
+
+    await approve(emergencyMember2, unlockAmount);
+
+    const emergencyRecovery = await program.account.emergencyRecovery.fetch(emergencyRecoveryPda);
+    assert.equal(emergencyRecovery.pendingUnlockAmount.toNumber(), unlockAmount.toNumber());
+    assert.equal(emergencyRecovery.pendingUnlockRecipient.toString(), emergencyMember1.publicKey.toString());
+    assert.equal(emergencyRecovery.unlockApprovals.length, 2);
+  });
+
   it("Allows player to burn tokens for monthly pass", async () => {
-    const burnAmount = 10000000; // 10 WZN
+    const burnAmount = 10000000; // 10 WZN for one month
 
     await program.methods
-      .burnToPlay(new anchor.BN(burnAmount))
+      .burnToPlay(RANKED_TIER, 1)
       .accounts({
         gameState: gameStatePda,
         burnVault: burnVaultPda,
+        passTier: rankedTierPda,
+        feeConfig: feeConfigPda,
+        priceFeed: null,
         playerPass: playerPassPda,
         playerTokenAccount: playerTokenAccount,
         burnVaultTokenAccount: burnVaultTokenAccount,
+        // Everything routes to the burn vault, so the other vaults can be left out
+        recoveryVault: null,
+        recoveryVaultTokenAccount: null,
+        prizeVault: null,
+        prizeVaultTokenAccount: null,
         player: player.publicKey,
+        feePayer: player.publicKey,
         wznMint: wznMint,
         tokenProgram: TOKEN_PROGRAM_ID,
+        passMint: null,
+        passTokenAccount: null,
+        passAuthority: null,
+        passTokenProgram: null,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       })
@@ -256,24 +543,418 @@ describe("wzn-burn-play", () => {
     const playerPass = await program.account.playerPass.fetch(playerPassPda);
 
     assert.equal(gameState.totalBurned.toNumber(), burnAmount);
+    assert.equal(gameState.totalLocked.toNumber(), burnAmount);
+    assert.equal(gameState.totalDestroyed.toNumber(), 0);
     assert.equal(burnVault.totalLocked.toNumber(), burnAmount);
     assert.equal(playerPass.isActive, true);
+    assert.equal(playerPass.tierId, RANKED_TIER);
     assert.equal(playerPass.totalPassesPurchased, 1);
+
+    const vaultTokenAccount = await getAccount(provider.connection, burnVaultTokenAccount);
+    assert.equal(Number(vaultTokenAccount.amount), burnAmount);
+  });
+
+  it("Stacks multi-month renewals on the remaining pass time", async () => {
+    const monthSeconds = 30 * 24 * 60 * 60;
+    const before = await program.account.playerPass.fetch(playerPassPda);
+
+    await program.methods
+      .burnToPlay(RANKED_TIER, 2)
+      .accounts({
+        gameState: gameStatePda,
+        burnVault: burnVaultPda,
+        passTier: rankedTierPda,
+        feeConfig: feeConfigPda,
+        priceFeed: null,
+        playerPass: playerPassPda,
+        playerTokenAccount: playerTokenAccount,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        recoveryVault: recoveryVaultPda,
+        recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+        prizeVault: prizeVaultPda,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        player: player.publicKey,
+        feePayer: player.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        passMint: null,
+        passTokenAccount: null,
+        passAuthority: null,
+        passTokenProgram: null,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([player])
+      .rpc();
+
+    const playerPass = await program.account.playerPass.fetch(playerPassPda);
+    assert.equal(playerPass.passStartTime.toNumber(), before.passStartTime.toNumber());
+    assert.equal(playerPass.passEndTime.toNumber(), before.passEndTime.toNumber() + 2 * monthSeconds);
+    assert.equal(playerPass.totalPassesPurchased, 3);
+    assert.equal(playerPass.totalTokensBurned.toNumber(), 30000000);
+  });
+
+  it("Rejects purchases beyond the prepaid horizon", async () => {
+    try {
+      await program.methods
+        .burnToPlay(RANKED_TIER, 12)
+        .accounts({
+          gameState: gameStatePda,
+          burnVault: burnVaultPda,
+          passTier: rankedTierPda,
+          feeConfig: feeConfigPda,
+          priceFeed: null,
+          playerPass: playerPassPda,
+          playerTokenAccount: playerTokenAccount,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          recoveryVault: recoveryVaultPda,
+          recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+          prizeVault: prizeVaultPda,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          player: player.publicKey,
+          feePayer: player.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([player])
+        .rpc();
+      assert.fail("Expected prepaid horizon to be enforced");
+    } catch (err) {
+      assert.include(err.toString(), "PrepaidHorizonExceeded");
+    }
+  });
+
+  it("Rejects paying from a token account the player does not own", async () => {
+    try {
+      await program.methods
+        .burnToPlay(RANKED_TIER, 1)
+        .accounts({
+          gameState: gameStatePda,
+          burnVault: burnVaultPda,
+          passTier: rankedTierPda,
+          feeConfig: feeConfigPda,
+          priceFeed: null,
+          playerPass: playerPassPda,
+          playerTokenAccount: authorityTokenAccount,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          recoveryVault: recoveryVaultPda,
+          recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+          prizeVault: prizeVaultPda,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          player: player.publicKey,
+          feePayer: player.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([player])
+        .rpc();
+      assert.fail("Expected a foreign token account to be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidTokenAccount");
+    }
+  });
+
+  it("Rejects a sponsor fee payer while players pay their own fees", async () => {
+    try {
+      await program.methods
+        .burnToPlay(RANKED_TIER, 1)
+        .accounts({
+          gameState: gameStatePda,
+          burnVault: burnVaultPda,
+          passTier: rankedTierPda,
+          feeConfig: feeConfigPda,
+          priceFeed: null,
+          playerPass: playerPassPda,
+          playerTokenAccount: playerTokenAccount,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          recoveryVault: recoveryVaultPda,
+          recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+          prizeVault: prizeVaultPda,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          player: player.publicKey,
+          feePayer: authority.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([player, authority])
+        .rpc();
+      assert.fail("Expected fee payer to be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "FeePayerNotApproved");
+    }
+  });
+
+  it("Allows sponsoring a pass for another wallet", async () => {
+    const [giftPassPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player_pass"), giftRecipient.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .burnToPlayFor(CASUAL_TIER, 1, giftRecipient.publicKey)
+      .accounts({
+        gameState: gameStatePda,
+        burnVault: burnVaultPda,
+        passTier: casualTierPda,
+        priceFeed: null,
+        playerPass: giftPassPda,
+        sponsorTokenAccount: authorityTokenAccount,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        recoveryVault: recoveryVaultPda,
+        recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+        prizeVault: prizeVaultPda,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        sponsor: authority.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        passMint: null,
+        passTokenAccount: null,
+        passAuthority: null,
+        passTokenProgram: null,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority])
+      .rpc();
+
+    const giftPass = await program.account.playerPass.fetch(giftPassPda);
+    assert.equal(giftPass.player.toString(), giftRecipient.publicKey.toString());
+    assert.equal(giftPass.sponsor.toString(), authority.publicKey.toString());
+    assert.equal(giftPass.tierId, CASUAL_TIER);
+    assert.equal(giftPass.isActive, true);
+    assert.equal(giftPass.rentPayer.toString(), authority.publicKey.toString());
+  });
+
+  it("Keeps running passes open and returns rent only to the payer", async () => {
+    const [giftPassPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player_pass"), giftRecipient.publicKey.toBuffer()],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .closePlayerPass()
+        .accounts({
+          gameState: gameStatePda,
+          playerPass: playerPassPda,
+          rentPayer: player.publicKey,
+          player: player.publicKey,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+        })
+        .signers([player])
+        .rpc();
+      assert.fail("Expected a running pass to stay open");
+    } catch (err) {
+      assert.include(err.toString(), "PassStillInUse");
+    }
+
+    // The crank cannot redirect sponsored rent to someone else
+    try {
+      await program.methods
+        .closeInactivePlayerPass(giftRecipient.publicKey)
+        .accounts({
+          gameState: gameStatePda,
+          playerPass: giftPassPda,
+          rentPayer: player.publicKey,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+        })
+        .rpc();
+      assert.fail("Expected the rent payer to be enforced");
+    } catch (err) {
+      assert.include(err.toString(), "NotAuthorized");
+    }
+
+    try {
+      await program.methods
+        .closeInactivePlayerPass(giftRecipient.publicKey)
+        .accounts({
+          gameState: gameStatePda,
+          playerPass: giftPassPda,
+          rentPayer: authority.publicKey,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+        })
+        .rpc();
+      assert.fail("Expected a running pass to stay open");
+    } catch (err) {
+      assert.include(err.toString(), "PassStillInUse");
+    }
+  });
+
+  it("Lets moderators suspend and reinstate a pass without losing paid days", async () => {
+    const [giftPassPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player_pass"), giftRecipient.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeRoleRegistry([moderator.publicKey])
+      .accounts({
+        roleRegistry: roleRegistryPda,
+        gameState: gameStatePda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const before = await program.account.playerPass.fetch(giftPassPda);
+    const daySeconds = 24 * 60 * 60;
+    const suspensionEnd = Math.floor(Date.now() / 1000) + daySeconds;
+
+    await program.methods
+      .suspendPass(giftRecipient.publicKey, 1, new anchor.BN(suspensionEnd))
+      .accounts({
+        gameState: gameStatePda,
+        roleRegistry: roleRegistryPda,
+        playerPass: giftPassPda,
+        moderator: moderator.publicKey,
+        passMint: null,
+        passTokenAccount: null,
+        passAuthority: null,
+        passTokenProgram: null,
+      })
+      .signers([moderator])
+      .rpc();
+
+    const suspended = await program.account.playerPass.fetch(giftPassPda);
+    assert.equal(suspended.isSuspended, true);
+    assert.equal(suspended.suspensionReason, 1);
+    assert(suspended.passEndTime.toNumber() > before.passEndTime.toNumber());
+
+    await program.methods
+      .reinstatePass(giftRecipient.publicKey)
+      .accounts({
+        gameState: gameStatePda,
+        roleRegistry: roleRegistryPda,
+        playerPass: giftPassPda,
+        moderator: moderator.publicKey,
+        passMint: null,
+        passTokenAccount: null,
+        passAuthority: null,
+        passTokenProgram: null,
+      })
+      .signers([moderator])
+      .rpc();
+
+    const reinstated = await program.account.playerPass.fetch(giftPassPda);
+    assert.equal(reinstated.isSuspended, false);
+    assert(reinstated.passEndTime.toNumber() >= before.passEndTime.toNumber());
+    assert(reinstated.passEndTime.toNumber() < suspended.passEndTime.toNumber());
+  });
+
+  it("Lets players without a pass use their free monthly games", async () => {
+    const [freePassPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player_pass"), freePlayer.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // A wallet the program has never seen gets its pass account on its first ticket
+    const playFreeGame = (nonce: number) =>
+      program.methods
+        .checkGameAccess({ casual: {} })
+        .accounts({
+          gameState: gameStatePda,
+          feeConfig: feeConfigPda,
+          playerPass: freePassPda,
+          passTier: null,
+          matchTicket: matchTicketPda(freePlayer.publicKey, nonce),
+          player: freePlayer.publicKey,
+          feePayer: freePlayer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([freePlayer])
+        .rpc();
+
+    await playFreeGame(0);
+    await playFreeGame(1);
+
+    const freePass = await program.account.playerPass.fetch(freePassPda);
+    assert.equal(freePass.freeGamesUsed, 2);
+    assert.equal(freePass.isActive, false);
+    const ticket = await program.account.matchTicket.fetch(matchTicketPda(freePlayer.publicKey, 1));
+    assert.equal(ticket.freeGame, true);
+
+    try {
+      await playFreeGame(2);
+      assert.fail("Expected free quota to be exhausted");
+    } catch (err) {
+      assert.include(err.toString(), "FreeQuotaExhausted");
+    }
   });
 
   it("Allows checking game access", async () => {
     await program.methods
-      .checkGameAccess()
+      .checkGameAccess({ ranked: {} })
       .accounts({
         gameState: gameStatePda,
+        feeConfig: feeConfigPda,
         playerPass: playerPassPda,
+        passTier: rankedTierPda,
+        matchTicket: matchTicketPda(player.publicKey, 0),
         player: player.publicKey,
+        feePayer: player.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .signers([player])
       .rpc();
 
-    // If we reach here, access was granted
-    assert(true);
+    const ticket = await program.account.matchTicket.fetch(matchTicketPda(player.publicKey, 0));
+    assert.equal(ticket.player.toString(), player.publicKey.toString());
+    assert.deepEqual(ticket.mode, { ranked: {} });
+    assert.equal(ticket.freeGame, false);
+    assert(ticket.expiresAt.toNumber() > ticket.issuedAt.toNumber());
+
+    const playerPass = await program.account.playerPass.fetch(playerPassPda);
+    assert.equal(playerPass.ticketNonce.toNumber(), 1);
+  });
+
+  it("Rejects game modes outside the pass tier", async () => {
+    try {
+      await program.methods
+        .checkGameAccess({ tournament: {} })
+        .accounts({
+          gameState: gameStatePda,
+          feeConfig: feeConfigPda,
+          playerPass: playerPassPda,
+          passTier: rankedTierPda,
+          matchTicket: matchTicketPda(player.publicKey, 1),
+          player: player.publicKey,
+          feePayer: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+      assert.fail("Expected tournament access to be denied");
+    } catch (err) {
+      assert.include(err.toString(), "GameModeNotAllowed");
+    }
   });
 
   it("Allows depositing to prize vault", async () => {
@@ -347,8 +1028,17 @@ describe("wzn-burn-play", () => {
         daoGovernance: daoGovernancePda,
         burnVault: burnVaultPda,
         prizeVault: prizeVaultPda,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
         gameState: gameStatePda,
+        feeConfig: feeConfigPda,
+        recoveryVault: null,
+        recoveryVaultTokenAccount: null,
+        recipientTokenAccount: null,
+        playerScore: null,
+        playerPass: null,
         executor: daoMember1.publicKey,
+        wznMint: wznMint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([daoMember1])
@@ -358,63 +1048,1495 @@ describe("wzn-burn-play", () => {
     assert.equal(gameState.monthlyPassCost.toNumber(), 15000000); // Updated to 15 WZN
   });
 
-  it("Allows updating player score", async () => {
+  it("Destroys the governed burn share and locks the rest", async () => {
     await program.methods
-      .updatePlayerScore(5, 3, 50) // 5 games played, 3 won, +50 rating
+      .createProposal({ updateBurnBps: {} }, new anchor.BN(5000), "Burn half of every purchase")
       .accounts({
-        playerScore: playerScorePda,
-        playerPass: playerPassPda,
-        player: player.publicKey,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY,
+        daoGovernance: daoGovernancePda,
+        proposer: daoMember1.publicKey,
       })
-      .signers([player])
+      .signers([daoMember1])
       .rpc();
 
-    const playerScore = await program.account.playerScore.fetch(playerScorePda);
-    assert.equal(playerScore.totalGamesPlayed, 5);
-    assert.equal(playerScore.totalGamesWon, 3);
-    assert.equal(playerScore.currentRating, 1050); // 1000 + 50
-  });
-
-  it("Allows distributing prizes", async () => {
-    const prizeAmount = 10000000; // 10 WZN
+    for (const member of [daoMember1, daoMember2]) {
+      await program.methods
+        .voteOnProposal(1, true)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          voter: member.publicKey,
+        })
+        .signers([member])
+        .rpc();
+    }
 
     await program.methods
-      .distributePrize(new anchor.BN(prizeAmount))
+      .executeProposal(1)
       .accounts({
+        daoGovernance: daoGovernancePda,
+        burnVault: burnVaultPda,
         prizeVault: prizeVaultPda,
+        burnVaultTokenAccount: burnVaultTokenAccount,
         prizeVaultTokenAccount: prizeVaultTokenAccount,
-        recipientTokenAccount: playerTokenAccount,
-        playerScore: playerScorePda,
         gameState: gameStatePda,
-        recipient: player.publicKey,
+        feeConfig: feeConfigPda,
+        recoveryVault: null,
+        recoveryVaultTokenAccount: null,
+        recipientTokenAccount: null,
+        playerScore: null,
+        playerPass: null,
+        executor: daoMember1.publicKey,
         wznMint: wznMint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .signers([player])
+      .signers([daoMember1])
       .rpc();
 
-    const prizeVault = await program.account.prizeVault.fetch(prizeVaultPda);
-    const playerScore = await program.account.playerScore.fetch(playerScorePda);
-    const gameState = await program.account.gameState.fetch(gameStatePda);
-
-    assert.equal(prizeVault.totalDistributed.toNumber(), prizeAmount);
-    assert.equal(playerScore.totalPrizesEarned.toNumber(), prizeAmount);
-    assert.equal(gameState.totalPrizesDistributed.toNumber(), prizeAmount);
-  });
+    const before = await program.account.gameState.fetch(gameStatePda);
+    const supplyBefore = (await getMint(provider.connection, wznMint)).supply;
+    assert.equal(before.burnBps, 5000);
 
-  it("Allows monthly reset", async () => {
     await program.methods
-      .monthlyReset()
+      .burnToPlay(RANKED_TIER, 1)
       .accounts({
         gameState: gameStatePda,
-        authority: authority.publicKey,
+        burnVault: burnVaultPda,
+        passTier: rankedTierPda,
+        feeConfig: feeConfigPda,
+        priceFeed: null,
+        playerPass: playerPassPda,
+        playerTokenAccount: playerTokenAccount,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        recoveryVault: recoveryVaultPda,
+        recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+        prizeVault: prizeVaultPda,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        player: player.publicKey,
+        feePayer: player.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        passMint: null,
+        passTokenAccount: null,
+        passAuthority: null,
+        passTokenProgram: null,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
       })
-      .signers([authority])
+      .signers([player])
       .rpc();
 
-    const gameState = await program.account.gameState.fetch(gameStatePda);
-    assert(gameState.lastMonthlyReset > 0);
+    // 15 WZN pass: 7.5 WZN destroyed, 7.5 WZN locked
+    const after = await program.account.gameState.fetch(gameStatePda);
+    const supplyAfter = (await getMint(provider.connection, wznMint)).supply;
+    assert.equal(after.totalDestroyed.toNumber() - before.totalDestroyed.toNumber(), 7500000);
+    assert.equal(after.totalLocked.toNumber() - before.totalLocked.toNumber(), 7500000);
+    assert.equal(Number(supplyBefore - supplyAfter), 7500000);
+  });
+
+  it("Shortens the provisional rating period through governance", async () => {
+    let gameState = await program.account.gameState.fetch(gameStatePda);
+    assert.equal(gameState.provisionalGames, 10);
+    assert.equal(gameState.ratingDecayWeeks, 4);
+    assert.equal(gameState.ratingFloor, 1000);
+
+    // One provisional game is enough for the match tests to reach the leaderboard
+    await program.methods
+      .createProposal({ updateProvisionalGames: {} }, new anchor.BN(1), "Settle ratings after one game")
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        proposer: daoMember1.publicKey,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    for (const member of [daoMember1, daoMember2]) {
+      await program.methods
+        .voteOnProposal(2, true)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          voter: member.publicKey,
+        })
+        .signers([member])
+        .rpc();
+    }
+
+    await program.methods
+      .executeProposal(2)
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        burnVault: burnVaultPda,
+        prizeVault: prizeVaultPda,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        gameState: gameStatePda,
+        feeConfig: feeConfigPda,
+        recoveryVault: null,
+        recoveryVaultTokenAccount: null,
+        recipientTokenAccount: null,
+        playerScore: null,
+        playerPass: null,
+        executor: daoMember1.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    gameState = await program.account.gameState.fetch(gameStatePda);
+    assert.equal(gameState.provisionalGames, 1);
+  });
+
+  it("Records match results reported by a registered game server", async () => {
+    const gameServer = Keypair.generate();
+    const seats = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    const sig = await provider.connection.requestAirdrop(gameServer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig);
+
+    await program.methods
+      .updateGameServers([gameServer.publicKey])
+      .accounts({
+        roleRegistry: roleRegistryPda,
+        gameState: gameStatePda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    // Results count towards the current season's leaderboard, which anyone may open
+    await program.methods
+      .openLeaderboard()
+      .accounts({
+        gameState: gameStatePda,
+        leaderboard: leaderboardPda(1),
+        payer: gameServer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([gameServer])
+      .rpc();
+
+    // The other three seats join a casual table on their free monthly games
+    for (const seat of seats) {
+      await seatFreePlayer(seat);
+    }
+
+    // The pass holder takes a casual seat too; every ticket must match the table's mode
+    await seatPassHolder(1);
+
+    const players = [player.publicKey, ...seats.map((seat) => seat.publicKey)];
+    const ticketNonces = [1, 0, 0, 0];
+    const gameId = new anchor.BN(1);
+    const logHash = Array.from(Buffer.alloc(32, 7));
+    // Seats 0 and 2 bid a partnership contract and make it
+    const result = {
+      mode: { casual: {} },
+      contract: { partnership: {} },
+      declarers: 0b0101,
+      placements: [1, 2, 1, 2],
+      tricks: [8, 2, 2, 1],
+      logHash,
+    };
+    const scorePdas = players.map(playerScorePdaFor);
+    const reportAccounts = (signer: PublicKey) => ({
+      gameState: gameStatePda,
+      roleRegistry: roleRegistryPda,
+      matchRecord: matchPda(gameId),
+      leaderboard: leaderboardPda(1),
+      playerScore0: scorePdas[0],
+      playerScore1: scorePdas[1],
+      playerScore2: scorePdas[2],
+      playerScore3: scorePdas[3],
+      matchTicket0: matchTicketPda(players[0], ticketNonces[0]),
+      matchTicket1: matchTicketPda(players[1], ticketNonces[1]),
+      matchTicket2: matchTicketPda(players[2], ticketNonces[2]),
+      matchTicket3: matchTicketPda(players[3], ticketNonces[3]),
+      ticketPayer0: players[0],
+      ticketPayer1: players[1],
+      ticketPayer2: players[2],
+      ticketPayer3: players[3],
+      gameServer: signer,
+      systemProgram: SystemProgram.programId,
+    });
+
+    // Players can no longer report their own results
+    try {
+      await program.methods
+        .reportMatchResult(gameId, players, result)
+        .accounts(reportAccounts(player.publicKey))
+        .signers([player])
+        .rpc();
+      assert.fail("Expected only game servers to report results");
+    } catch (err) {
+      assert.include(err.toString(), "NotAuthorized");
+    }
+
+    await program.methods
+      .reportMatchResult(gameId, players, result)
+      .accounts(reportAccounts(gameServer.publicKey))
+      .signers([gameServer])
+      .rpc();
+
+    const matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.equal(matchRecord.gameId.toNumber(), 1);
+    assert.deepEqual(matchRecord.mode, { casual: {} });
+    assert.deepEqual(matchRecord.placements, [1, 2, 1, 2]);
+    assert.deepEqual(matchRecord.logHash, logHash);
+    assert.equal(matchRecord.players[3].toString(), seats[2].publicKey.toString());
+    assert(matchRecord.endedAt.toNumber() >= matchRecord.startedAt.toNumber());
+    // Every seat is still provisional, so the swing uses the larger K = 64
+    assert.deepEqual(matchRecord.ratingChanges, [32, -32, 32, -32]);
+
+    const playerScore = await program.account.playerScore.fetch(playerScorePda);
+    assert.equal(playerScore.totalGamesPlayed, 1);
+    assert.equal(playerScore.totalGamesWon, 1);
+    assert.equal(playerScore.totalTricksTaken, 8);
+    assert.equal(playerScore.currentRating, 1032); // Even partnerships trade half of K
+    assert.equal(playerScore.partnershipGames, 1);
+    assert.equal(playerScore.contractsBid, 1);
+    assert.equal(playerScore.contractsMade, 1);
+    assert.equal(playerScore.currentWinStreak, 1);
+    assert.equal(playerScore.bestWinStreak, 1);
+    assert.equal(playerScore.partnerBuckets, 1);
+    assert.equal(playerScore.monthlyPoints, 3);
+
+    // Winners lead on points; equal points and rating fall back to the lower key
+    const leaderboard = await program.account.leaderboard.fetch(leaderboardPda(1));
+    assert.equal(leaderboard.entries.length, 4);
+    assert.deepEqual(
+      leaderboard.entries.map((entry) => entry.monthlyPoints),
+      [3, 3, 1, 1]
+    );
+    const winners = [players[0], players[2]].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+    assert.equal(leaderboard.entries[0].player.toString(), winners[0].toString());
+    const playerIndex = leaderboard.entries.findIndex((entry) => entry.player.equals(player.publicKey));
+    assert.equal(playerScore.monthlyRank, playerIndex + 1);
+    assert.equal(playerScore.rentPayer.toString(), gameServer.publicKey.toString());
+
+    const loserScore = await program.account.playerScore.fetch(scorePdas[1]);
+    assert.equal(loserScore.totalGamesPlayed, 1);
+    assert.equal(loserScore.totalGamesWon, 0);
+    assert.equal(loserScore.currentRating, 968);
+    assert.equal(loserScore.contractsBid, 0);
+    assert.equal(loserScore.currentWinStreak, 0);
+
+    // Scores created with the current layout have nothing to migrate
+    try {
+      await program.methods
+        .migratePlayerScore(player.publicKey)
+        .accounts({
+          playerScore: playerScorePda,
+          payer: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+      assert.fail("Expected the score to be up to date");
+    } catch (err) {
+      assert.include(err.toString(), "ScoreAlreadyMigrated");
+    }
+
+    // The tickets are consumed and cannot be reported twice
+    for (let seat = 0; seat < players.length; seat++) {
+      const ticket = await program.account.matchTicket.fetchNullable(matchTicketPda(players[seat], ticketNonces[seat]));
+      assert.isNull(ticket);
+    }
+
+    // The existing match record blocks a replay of the same game before any ticket is touched
+    try {
+      await program.methods
+        .reportMatchResult(gameId, players, result)
+        .accounts(reportAccounts(gameServer.publicKey))
+        .signers([gameServer])
+        .rpc();
+      assert.fail("Expected the replayed game to be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "0x0"); // System program: account already in use
+    }
+  });
+
+  it("Records a player-proposed result once the table reaches the attestation threshold", async () => {
+    const seats = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    for (const seat of seats) {
+      await seatFreePlayer(seat);
+    }
+    await seatPassHolder(2);
+
+    const players = [player.publicKey, ...seats.map((seat) => seat.publicKey)];
+    const ticketNonces = [2, 0, 0, 0];
+    const gameId = new anchor.BN(2);
+    const scorePdas = players.map(playerScorePdaFor);
+    const scoreAccounts = {
+      playerScore0: scorePdas[0],
+      playerScore1: scorePdas[1],
+      playerScore2: scorePdas[2],
+      playerScore3: scorePdas[3],
+    };
+
+    const gameState = await program.account.gameState.fetch(gameStatePda);
+    assert.equal(gameState.attestationThreshold, 3);
+
+    const ticketAccounts = {
+      matchTicket0: matchTicketPda(players[0], ticketNonces[0]),
+      matchTicket1: matchTicketPda(players[1], ticketNonces[1]),
+      matchTicket2: matchTicketPda(players[2], ticketNonces[2]),
+      matchTicket3: matchTicketPda(players[3], ticketNonces[3]),
+    };
+    const propose = (placements: number[]) =>
+      program.methods
+        .proposeMatchResult(gameId, players, {
+          mode: { casual: {} },
+          contract: { partnership: {} },
+          declarers: 0b1010,
+          placements,
+          tricks: [3, 5, 2, 3],
+          logHash: Array.from(Buffer.alloc(32, 9)),
+        })
+        .accounts({
+          gameState: gameStatePda,
+          matchRecord: matchPda(gameId),
+          ...ticketAccounts,
+          proposer: seats[0].publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seats[0]])
+        .rpc();
+    const reject = (seat: Keypair) =>
+      program.methods
+        .rejectMatchResult(gameId)
+        .accounts({
+          gameState: gameStatePda,
+          matchRecord: matchPda(gameId),
+          ...ticketAccounts,
+          ticketPayer0: players[0],
+          ticketPayer1: players[1],
+          ticketPayer2: players[2],
+          ticketPayer3: players[3],
+          rejecter: seat.publicKey,
+        })
+        .signers([seat])
+        .rpc();
+
+    // Seats 1 and 3 win; seat 1 proposes and counts as the first signature
+    await propose([2, 1, 2, 1]);
+
+    // Tickets held by a proposal cannot be cleaned up from under the attesters
+    try {
+      await program.methods
+        .closeExpiredTicket()
+        .accounts({
+          matchTicket: ticketAccounts.matchTicket0,
+          ticketPayer: players[0],
+        })
+        .rpc();
+      assert.fail("Expected the held ticket to stay open");
+    } catch (err) {
+      assert.include(err.toString(), "MatchTicketStillValid");
+    }
+
+    // A single rejection leaves the threshold within reach of the other three seats
+    await reject(player);
+    let matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.deepEqual(matchRecord.status, { awaitingAttestation: {} });
+    assert.equal(matchRecord.rejections, 0b0001);
+
+    const attest = (seat: Keypair) =>
+      program.methods
+        .attestMatchResult(gameId)
+        .accounts({
+          gameState: gameStatePda,
+          matchRecord: matchPda(gameId),
+          leaderboard: leaderboardPda(1),
+          ...scoreAccounts,
+          ...ticketAccounts,
+          ticketPayer0: players[0],
+          ticketPayer1: players[1],
+          ticketPayer2: players[2],
+          ticketPayer3: players[3],
+          attester: seat.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seat])
+        .rpc();
+
+    const scoreBefore = await program.account.playerScore.fetch(playerScorePda);
+
+    // Two colluding seats are not enough to record anything
+    await attest(seats[2]);
+    matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.deepEqual(matchRecord.status, { awaitingAttestation: {} });
+    assert.equal(matchRecord.attestations, 0b1010);
+    assert.isNull(await program.account.playerScore.fetchNullable(scorePdas[1]));
+
+    try {
+      await attest(seats[2]);
+      assert.fail("Expected a seat to attest only once");
+    } catch (err) {
+      assert.include(err.toString(), "AlreadyAttested");
+    }
+
+    // The third signature reaches the threshold even though seat 0 rejected
+    await attest(seats[1]);
+    matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.deepEqual(matchRecord.status, { pending: {} });
+
+    // Reaching the threshold consumes the tickets
+    for (let seat = 0; seat < players.length; seat++) {
+      assert.isNull(await program.account.matchTicket.fetchNullable(matchTicketPda(players[seat], ticketNonces[seat])));
+    }
+
+    const scoreAfter = await program.account.playerScore.fetch(playerScorePda);
+    assert.equal(scoreAfter.totalGamesPlayed, scoreBefore.totalGamesPlayed + 1);
+    assert.equal(scoreAfter.totalGamesWon, scoreBefore.totalGamesWon);
+    assert.equal(scoreAfter.currentWinStreak, 0);
+    assert.equal(scoreAfter.bestWinStreak, 1);
+    assert.isBelow(scoreAfter.currentRating, scoreBefore.currentRating);
+
+    const winnerScore = await program.account.playerScore.fetch(scorePdas[1]);
+    assert.equal(winnerScore.totalGamesWon, 1);
+    assert.equal(winnerScore.totalTricksTaken, 5);
+  });
+
+  it("Rolls back a disputed match voided by an arbiter", async () => {
+    const gameId = new anchor.BN(1);
+    const finalizeAccounts = async (id: anchor.BN) => {
+      const record = await program.account.match.fetch(matchPda(id));
+      return {
+        gameState: gameStatePda,
+        matchRecord: matchPda(id),
+        playerScore0: playerScorePdaFor(record.players[0]),
+        playerScore1: playerScorePdaFor(record.players[1]),
+        playerScore2: playerScorePdaFor(record.players[2]),
+        playerScore3: playerScorePdaFor(record.players[3]),
+      };
+    };
+
+    // Undisputed results only become final once the window has passed
+    try {
+      await program.methods.finalizeMatch(new anchor.BN(2)).accounts(await finalizeAccounts(new anchor.BN(2))).rpc();
+      assert.fail("Expected the dispute window to still be open");
+    } catch (err) {
+      assert.include(err.toString(), "DisputeWindowOpen");
+    }
+
+    const evidenceHash = Array.from(Buffer.alloc(32, 3));
+    await program.methods
+      .disputeMatch(gameId, evidenceHash)
+      .accounts({
+        gameState: gameStatePda,
+        matchRecord: matchPda(gameId),
+        disputer: player.publicKey,
+      })
+      .signers([player])
+      .rpc();
+
+    let matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.deepEqual(matchRecord.status, { disputed: {} });
+    assert.equal(matchRecord.disputedBy.toString(), player.publicKey.toString());
+    assert.deepEqual(matchRecord.evidenceHash, evidenceHash);
+
+    const before = await program.account.playerScore.fetch(playerScorePda);
+
+    // The game authority arbitrates until dedicated arbiters are registered
+    await program.methods
+      .resolveDispute(gameId, true)
+      .accounts({
+        gameState: gameStatePda,
+        roleRegistry: roleRegistryPda,
+        matchRecord: matchPda(gameId),
+        leaderboard: leaderboardPda(matchRecord.season),
+        seasonRecord: null,
+        playerScore0: playerScorePdaFor(matchRecord.players[0]),
+        playerScore1: playerScorePdaFor(matchRecord.players[1]),
+        playerScore2: playerScorePdaFor(matchRecord.players[2]),
+        playerScore3: playerScorePdaFor(matchRecord.players[3]),
+        arbiter: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.deepEqual(matchRecord.status, { voided: {} });
+
+    const after = await program.account.playerScore.fetch(playerScorePda);
+    assert.equal(after.totalGamesPlayed, before.totalGamesPlayed - 1);
+    assert.equal(after.totalGamesWon, before.totalGamesWon - 1);
+    assert.equal(after.totalTricksTaken, before.totalTricksTaken - 8);
+    assert.equal(after.currentRating, before.currentRating - matchRecord.ratingChanges[0]);
+    assert.equal(after.partnershipGames, before.partnershipGames - 1);
+    assert.equal(after.contractsMade, before.contractsMade - 1);
+    assert.equal(after.monthlyPoints, before.monthlyPoints - 3);
+    assert.equal(after.openMatches, before.openMatches - 1);
+
+    const leaderboard = await program.account.leaderboard.fetch(leaderboardPda(1));
+    const entry = leaderboard.entries.find((e) => e.player.equals(player.publicKey));
+    assert.equal(entry.monthlyPoints, after.monthlyPoints);
+    assert.equal(after.monthlyRank, leaderboard.entries.indexOf(entry) + 1);
+
+    const loserScore = await program.account.playerScore.fetch(playerScorePdaFor(matchRecord.players[1]));
+    assert.equal(loserScore.currentRating, 1000);
+
+    // Back to zero games, the seat is provisional again and drops off the public ladder
+    assert.equal(loserScore.totalGamesPlayed, 0);
+    assert.isUndefined(leaderboard.entries.find((e) => e.player.equals(matchRecord.players[1])));
+
+    // Seat 2 played no other game, so its peak, streaks and partners are back to before the voided win
+    const winnerScore = await program.account.playerScore.fetch(playerScorePdaFor(matchRecord.players[2]));
+    assert.equal(winnerScore.highestRating, 1000);
+    assert.equal(winnerScore.currentWinStreak, 0);
+    assert.equal(winnerScore.bestWinStreak, 0);
+    assert.equal(winnerScore.lastGameTime.toNumber(), 0);
+    assert.equal(winnerScore.partnerBuckets, 0);
+  });
+
+  it("Leaves a result rejected by the losing seats pending for an arbiter", async () => {
+    const seats = [Keypair.generate(), Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    for (const seat of seats) {
+      await seatFreePlayer(seat);
+    }
+
+    const players = seats.map((seat) => seat.publicKey);
+    const gameId = new anchor.BN(3);
+    const scorePdas = players.map(playerScorePdaFor);
+    const ticketAccounts = {
+      matchTicket0: matchTicketPda(players[0], 0),
+      matchTicket1: matchTicketPda(players[1], 0),
+      matchTicket2: matchTicketPda(players[2], 0),
+      matchTicket3: matchTicketPda(players[3], 0),
+    };
+
+    // Seats 0 and 2 win
+    await program.methods
+      .proposeMatchResult(gameId, players, {
+        mode: { casual: {} },
+        contract: { partnership: {} },
+        declarers: 0b0101,
+        placements: [1, 2, 1, 2],
+        tricks: [4, 3, 4, 2],
+        logHash: Array.from(Buffer.alloc(32, 7)),
+      })
+      .accounts({
+        gameState: gameStatePda,
+        matchRecord: matchPda(gameId),
+        ...ticketAccounts,
+        proposer: players[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([seats[0]])
+      .rpc();
+
+    const reject = (seat: Keypair) =>
+      program.methods
+        .rejectMatchResult(gameId)
+        .accounts({
+          gameState: gameStatePda,
+          matchRecord: matchPda(gameId),
+          ...ticketAccounts,
+          ticketPayer0: players[0],
+          ticketPayer1: players[1],
+          ticketPayer2: players[2],
+          ticketPayer3: players[3],
+          rejecter: seat.publicKey,
+        })
+        .signers([seat])
+        .rpc();
+
+    // Both losers reject: the threshold is out of reach, but the record stays for an arbiter to rule on
+    await reject(seats[1]);
+    await reject(seats[3]);
+    let matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.deepEqual(matchRecord.status, { contested: {} });
+    assert.equal(matchRecord.rejections, 0b1010);
+    assert.isTrue(matchRecord.disputedBy.equals(players[3]));
+
+    // The tickets are spent, so the losers cannot back a fresh proposal with them
+    for (let seat = 0; seat < players.length; seat++) {
+      assert.isNull(await program.account.matchTicket.fetchNullable(matchTicketPda(players[seat], 0)));
+      assert.isNull(await program.account.playerScore.fetchNullable(scorePdas[seat]));
+    }
+
+    // Nor can the stale-proposal cleanup remove it before the arbiter rules
+    try {
+      await program.methods
+        .closeStaleMatchProposal(gameId)
+        .accounts({ matchRecord: matchPda(gameId), reporter: players[0] })
+        .rpc();
+      assert.fail("Expected the contested match to stay open");
+    } catch (err) {
+      assert.include(err.toString(), "MatchNotAwaitingAttestation");
+    }
+
+    await program.methods
+      .resolveDispute(gameId, false)
+      .accounts({
+        gameState: gameStatePda,
+        roleRegistry: roleRegistryPda,
+        matchRecord: matchPda(gameId),
+        leaderboard: leaderboardPda(matchRecord.season),
+        seasonRecord: null,
+        playerScore0: scorePdas[0],
+        playerScore1: scorePdas[1],
+        playerScore2: scorePdas[2],
+        playerScore3: scorePdas[3],
+        arbiter: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    matchRecord = await program.account.match.fetch(matchPda(gameId));
+    assert.deepEqual(matchRecord.status, { final: {} });
+
+    const winnerScore = await program.account.playerScore.fetch(scorePdas[0]);
+    assert.equal(winnerScore.totalGamesWon, 1);
+    assert.equal(winnerScore.openMatches, 0);
+    assert.isTrue(winnerScore.rentPayer.equals(authority.publicKey));
+
+    const loserScore = await program.account.playerScore.fetch(scorePdas[1]);
+    assert.equal(loserScore.totalGamesPlayed, 1);
+    assert.isBelow(loserScore.currentRating, 1000);
+  });
+
+  it("Scores solo and misère contracts one against three without minting rating", async () => {
+    const reportSoloTable = async (id: number, result: any) => {
+      const seats = [Keypair.generate(), Keypair.generate(), Keypair.generate(), Keypair.generate()];
+      for (const seat of seats) {
+        await seatFreePlayer(seat);
+      }
+      const players = seats.map((seat) => seat.publicKey);
+      const gameId = new anchor.BN(id);
+
+      await program.methods
+        .reportMatchResult(gameId, players, result)
+        .accounts({
+          gameState: gameStatePda,
+          roleRegistry: roleRegistryPda,
+          matchRecord: matchPda(gameId),
+          leaderboard: leaderboardPda(1),
+          playerScore0: playerScorePdaFor(players[0]),
+          playerScore1: playerScorePdaFor(players[1]),
+          playerScore2: playerScorePdaFor(players[2]),
+          playerScore3: playerScorePdaFor(players[3]),
+          matchTicket0: matchTicketPda(players[0], 0),
+          matchTicket1: matchTicketPda(players[1], 0),
+          matchTicket2: matchTicketPda(players[2], 0),
+          matchTicket3: matchTicketPda(players[3], 0),
+          ticketPayer0: players[0],
+          ticketPayer1: players[1],
+          ticketPayer2: players[2],
+          ticketPayer3: players[3],
+          gameServer: gameServer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([gameServer])
+        .rpc();
+
+      const matchRecord = await program.account.match.fetch(matchPda(gameId));
+      const scores = await Promise.all(players.map((p) => program.account.playerScore.fetch(playerScorePdaFor(p))));
+      return { matchRecord, scores };
+    };
+
+    // Seat 0 makes a solo; the three defenders share the 32 points it takes, earlier seats paying the remainder
+    const solo = await reportSoloTable(4, {
+      mode: { casual: {} },
+      contract: { solo: {} },
+      declarers: 0b0001,
+      placements: [1, 2, 2, 2],
+      tricks: [9, 2, 1, 1],
+      logHash: Array.from(Buffer.alloc(32, 4)),
+    });
+    assert.deepEqual(solo.matchRecord.ratingChanges, [32, -11, -11, -10]);
+    assert.equal(solo.scores[0].soloGames, 1);
+    assert.equal(solo.scores[0].contractsMade, 1);
+    assert.equal(solo.scores[1].soloGames, 1);
+    assert.equal(solo.scores[1].contractsBid, 0);
+    // No partnership was formed, so no partner is recorded
+    assert.equal(solo.scores[0].partnerBuckets, 0);
+
+    // Seat 1 fails a misère; its loss is shared out among the other three
+    const misere = await reportSoloTable(5, {
+      mode: { casual: {} },
+      contract: { misere: {} },
+      declarers: 0b0010,
+      placements: [1, 2, 1, 1],
+      tricks: [4, 1, 4, 4],
+      logHash: Array.from(Buffer.alloc(32, 5)),
+    });
+    assert.deepEqual(misere.matchRecord.ratingChanges, [11, -32, 11, 10]);
+    assert.equal(misere.scores[1].misereGames, 1);
+    assert.equal(misere.scores[1].contractsBid, 1);
+    assert.equal(misere.scores[1].contractsMade, 0);
+    assert.equal(misere.scores[1].currentRating, 1000 - 32);
+
+    for (const { matchRecord } of [solo, misere]) {
+      assert.equal(matchRecord.ratingChanges.reduce((sum: number, change: number) => sum + change, 0), 0);
+    }
+  });
+
+  it("Syncs a cached monthly rank and keeps scores with a live pass open", async () => {
+    await program.methods
+      .syncMonthlyRank(player.publicKey)
+      .accounts({
+        gameState: gameStatePda,
+        leaderboard: leaderboardPda(1),
+        playerScore: playerScorePda,
+      })
+      .rpc();
+
+    const leaderboard = await program.account.leaderboard.fetch(leaderboardPda(1));
+    const playerScore = await program.account.playerScore.fetch(playerScorePda);
+    const position = leaderboard.entries.findIndex((e) => e.player.equals(player.publicKey));
+    assert.equal(playerScore.monthlyRank, position + 1);
+
+    // The player's pass is still running, so the score cannot be closed from under it
+    try {
+      await program.methods
+        .closePlayerScore()
+        .accounts({
+          gameState: gameStatePda,
+          playerScore: playerScorePda,
+          playerPass: playerPassPda,
+          rentPayer: playerScore.rentPayer,
+          player: player.publicKey,
+        })
+        .signers([player])
+        .rpc();
+      assert.fail("Expected the score to stay open");
+    } catch (err) {
+      assert.include(err.toString(), "PassStillInUse");
+    }
+  });
+
+  it("Allows distributing prizes", async () => {
+    const prizeAmount = 10000000; // 10 WZN
+
+    const claimPrize = (amount: number) =>
+      program.methods
+        .distributePrize(new anchor.BN(amount))
+        .accounts({
+          prizeVault: prizeVaultPda,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          recipientTokenAccount: playerTokenAccount,
+          playerScore: playerScorePda,
+          playerPass: playerPassPda,
+          passTier: rankedTierPda,
+          gameState: gameStatePda,
+          recipient: player.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([player])
+        .rpc();
+
+    const executeAward = (playerScore: PublicKey | null) =>
+      program.methods
+        .executeProposal(3)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          burnVault: burnVaultPda,
+          prizeVault: prizeVaultPda,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          gameState: gameStatePda,
+          feeConfig: null,
+          recoveryVault: null,
+          recoveryVaultTokenAccount: null,
+          recipientTokenAccount: null,
+          playerScore,
+          playerPass: null,
+          executor: daoMember1.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoMember1])
+        .rpc();
+
+    // Nothing has been awarded yet, so the vault cannot be drained by the recipient
+    try {
+      await claimPrize(prizeAmount);
+      assert.fail("Expected an unawarded claim to fail");
+    } catch (err) {
+      assert.include(err.toString(), "PrizeNotAwarded");
+    }
+
+    // Prizes are only awarded by a passed proposal naming the recipient
+    await program.methods
+      .createProposal({ distributePrizes: { recipient: player.publicKey } }, new anchor.BN(prizeAmount), "Monthly prize")
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        proposer: daoMember1.publicKey,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    for (const member of [daoMember1, daoMember2]) {
+      await program.methods
+        .voteOnProposal(3, true)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          voter: member.publicKey,
+        })
+        .signers([member])
+        .rpc();
+    }
+
+    // The award lands on the named player's score, so it cannot be credited to anyone else
+    const otherSeat = (await program.account.match.fetch(matchPda(new anchor.BN(2)))).players[1];
+    try {
+      await executeAward(playerScorePdaFor(otherSeat));
+      assert.fail("Expected another player's score to be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "PlayerScoreRequired");
+    }
+
+    await executeAward(playerScorePda);
+    let playerScore = await program.account.playerScore.fetch(playerScorePda);
+    assert.equal(playerScore.unclaimedPrizes.toNumber(), prizeAmount);
+
+    await claimPrize(prizeAmount);
+
+    const prizeVault = await program.account.prizeVault.fetch(prizeVaultPda);
+    playerScore = await program.account.playerScore.fetch(playerScorePda);
+    const gameState = await program.account.gameState.fetch(gameStatePda);
+
+    assert.equal(prizeVault.totalDistributed.toNumber(), prizeAmount);
+    assert.equal(playerScore.totalPrizesEarned.toNumber(), prizeAmount);
+    assert.equal(playerScore.unclaimedPrizes.toNumber(), 0);
+    assert.equal(gameState.totalPrizesDistributed.toNumber(), prizeAmount);
+
+    const vaultTokenAccount = await getAccount(provider.connection, prizeVaultTokenAccount);
+    assert.equal(Number(vaultTokenAccount.amount), 50000000 - prizeAmount);
+  });
+
+  it("Routes the locked share across vaults per the governed table", async () => {
+    // 75% to the burn vault, 15% to the recovery vault, 10% to the prize vault
+    const routes = [
+      { destination: { burnVault: {} }, bps: 7500 },
+      { destination: { recoveryVault: {} }, bps: 1500 },
+      { destination: { prizeVault: {} }, bps: 1000 },
+    ];
+
+    await program.methods
+      .createProposal({ updateBurnRoutes: { routes } }, new anchor.BN(0), "Route burns to recovery and prizes")
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        proposer: daoMember1.publicKey,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    for (const member of [daoMember1, daoMember2]) {
+      await program.methods
+        .voteOnProposal(4, true)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          voter: member.publicKey,
+        })
+        .signers([member])
+        .rpc();
+    }
+
+    await program.methods
+      .executeProposal(4)
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        burnVault: burnVaultPda,
+        prizeVault: prizeVaultPda,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        gameState: gameStatePda,
+        feeConfig: feeConfigPda,
+        recoveryVault: null,
+        recoveryVaultTokenAccount: null,
+        recipientTokenAccount: null,
+        playerScore: null,
+        playerPass: null,
+        executor: daoMember1.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    const burnBefore = await program.account.burnVault.fetch(burnVaultPda);
+    const recoveryBefore = await program.account.recoveryVault.fetch(recoveryVaultPda);
+    const prizeBefore = await program.account.prizeVault.fetch(prizeVaultPda);
+
+    await program.methods
+      .burnToPlay(RANKED_TIER, 1)
+      .accounts({
+        gameState: gameStatePda,
+        burnVault: burnVaultPda,
+        passTier: rankedTierPda,
+        feeConfig: feeConfigPda,
+        priceFeed: null,
+        playerPass: playerPassPda,
+        playerTokenAccount: playerTokenAccount,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        recoveryVault: recoveryVaultPda,
+        recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+        prizeVault: prizeVaultPda,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        player: player.publicKey,
+        feePayer: player.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        passMint: null,
+        passTokenAccount: null,
+        passAuthority: null,
+        passTokenProgram: null,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([player])
+      .rpc();
+
+    // 15 WZN pass at a 50% burn share leaves 7.5 WZN to route
+    const burnAfter = await program.account.burnVault.fetch(burnVaultPda);
+    const recoveryAfter = await program.account.recoveryVault.fetch(recoveryVaultPda);
+    const prizeAfter = await program.account.prizeVault.fetch(prizeVaultPda);
+    const burnLeg = burnAfter.totalLocked.toNumber() - burnBefore.totalLocked.toNumber();
+    const recoveryLeg = recoveryAfter.totalReceived.toNumber() - recoveryBefore.totalReceived.toNumber();
+    const prizeLeg = prizeAfter.totalDeposited.toNumber() - prizeBefore.totalDeposited.toNumber();
+
+    assert.equal(burnLeg, 5625000);
+    assert.equal(recoveryLeg, 1125000);
+    assert.equal(prizeLeg, 750000);
+    assert.equal(burnLeg + recoveryLeg + prizeLeg, 7500000);
+
+    const recoveryTokenAccount = await getAccount(provider.connection, recoveryVaultTokenAccount);
+    assert.equal(Number(recoveryTokenAccount.amount), recoveryLeg);
+
+    // Once a leg goes to the recovery vault it must be passed in
+    try {
+      await program.methods
+        .burnToPlay(RANKED_TIER, 1)
+        .accounts({
+          gameState: gameStatePda,
+          burnVault: burnVaultPda,
+          passTier: rankedTierPda,
+          feeConfig: feeConfigPda,
+          priceFeed: null,
+          playerPass: playerPassPda,
+          playerTokenAccount: playerTokenAccount,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          recoveryVault: null,
+          recoveryVaultTokenAccount: null,
+          prizeVault: prizeVaultPda,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          player: player.publicKey,
+          feePayer: player.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([player])
+        .rpc();
+      assert.fail("Expected the recovery vault to be required");
+    } catch (err) {
+      assert.include(err.toString(), "RouteVaultMissing");
+    }
+  });
+
+  it("Prices passes from the reference feed and falls back to the fixed cost", async () => {
+    const burnPeggedPass = async () => {
+      const before = await program.account.playerPass.fetch(playerPassPda);
+      await program.methods
+        .burnToPlay(RANKED_TIER, 1)
+        .accounts({
+          gameState: gameStatePda,
+          burnVault: burnVaultPda,
+          passTier: rankedTierPda,
+          feeConfig: feeConfigPda,
+          priceFeed: priceFeedPda,
+          playerPass: playerPassPda,
+          playerTokenAccount: playerTokenAccount,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          recoveryVault: recoveryVaultPda,
+          recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+          prizeVault: prizeVaultPda,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          player: player.publicKey,
+          feePayer: player.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          passMint: null,
+          passTokenAccount: null,
+          passAuthority: null,
+          passTokenProgram: null,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([player])
+        .rpc();
+      const after = await program.account.playerPass.fetch(playerPassPda);
+      return after.totalTokensBurned.toNumber() - before.totalTokensBurned.toNumber();
+    };
+
+    const publishPrice = (price: number, confidence: number) =>
+      program.methods
+        .updatePriceFeed(new anchor.BN(price), new anchor.BN(confidence), -2)
+        .accounts({
+          priceFeed: priceFeedPda,
+          updater: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    await program.methods
+      .initializePriceFeed(authority.publicKey)
+      .accounts({
+        priceFeed: priceFeedPda,
+        gameState: gameStatePda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    // $5.00 pass, max 60s old price, 1% confidence, 1..100 WZN bounds
+    await program.methods
+      .configurePricing(
+        { pegged: {} },
+        new anchor.BN(5000000),
+        new anchor.BN(60),
+        100,
+        new anchor.BN(1000000),
+        new anchor.BN(100000000)
+      )
+      .accounts({
+        gameState: gameStatePda,
+        priceFeed: priceFeedPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    // $0.25 per WZN prices the pass at 20 WZN
+    await publishPrice(25, 0);
+    assert.equal(await burnPeggedPass(), 20000000);
+
+    // A 40% confidence interval is too uncertain, so the fixed 15 WZN applies
+    await publishPrice(25, 10);
+    assert.equal(await burnPeggedPass(), 15000000);
+
+    await program.methods
+      .configurePricing({ fixed: {} }, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        gameState: gameStatePda,
+        priceFeed: null,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    const gameState = await program.account.gameState.fetch(gameStatePda);
+    assert.deepEqual(gameState.pricingMode, { fixed: {} });
+    assert.equal(gameState.priceFeed.toString(), priceFeedPda.toString());
+  });
+
+  it("Publishes scheduled pass cost steps set through governance", async () => {
+    const passProposal = async (id: number, proposalType: any, amount: number, description: string) => {
+      await program.methods
+        .createProposal(proposalType, new anchor.BN(amount), description)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          proposer: daoMember1.publicKey,
+        })
+        .signers([daoMember1])
+        .rpc();
+
+      for (const member of [daoMember1, daoMember2]) {
+        await program.methods
+          .voteOnProposal(id, true)
+          .accounts({
+            daoGovernance: daoGovernancePda,
+            voter: member.publicKey,
+          })
+          .signers([member])
+          .rpc();
+      }
+
+      await program.methods
+        .executeProposal(id)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          burnVault: burnVaultPda,
+          prizeVault: prizeVaultPda,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          gameState: gameStatePda,
+          feeConfig: feeConfigPda,
+          recoveryVault: null,
+          recoveryVaultTokenAccount: null,
+          recipientTokenAccount: null,
+          playerScore: null,
+          playerPass: null,
+          executor: daoMember1.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoMember1])
+        .rpc();
+    };
+
+    const effectiveAt = new anchor.BN(Math.floor(Date.now() / 1000) + 30 * 24 * 60 * 60);
+    await passProposal(5, { schedulePassCost: { effectiveAt } }, 20000000, "Raise pass to 20 WZN next month");
+
+    let gameState = await program.account.gameState.fetch(gameStatePda);
+    assert.equal(gameState.priceSchedule.length, 1);
+    assert.equal(gameState.priceSchedule[0].effectiveAt.toString(), effectiveAt.toString());
+    assert.equal(gameState.priceSchedule[0].cost.toNumber(), 20000000);
+
+    // Amending the same step replaces its cost; the current cost is untouched until it takes effect
+    await passProposal(6, { schedulePassCost: { effectiveAt } }, 25000000, "Raise pass to 25 WZN instead");
+
+    gameState = await program.account.gameState.fetch(gameStatePda);
+    assert.equal(gameState.priceSchedule.length, 1);
+    assert.equal(gameState.priceSchedule[0].cost.toNumber(), 25000000);
+    assert.equal(gameState.monthlyPassCost.toNumber(), 15000000);
+  });
+
+  it("Mirrors the pass as a soulbound Token-2022 token kept in sync on renewal", async () => {
+    const [passMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pass_mint"), player.publicKey.toBuffer()],
+      program.programId
+    );
+    const [passAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pass_authority")],
+      program.programId
+    );
+    const passTokenAccount = getAssociatedTokenAddressSync(passMint, player.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    await program.methods
+      .createPassToken()
+      .accounts({
+        feeConfig: feeConfigPda,
+        playerPass: playerPassPda,
+        passMint: passMint,
+        passAuthority: passAuthority,
+        passTokenAccount: passTokenAccount,
+        player: player.publicKey,
+        feePayer: player.publicKey,
+        passTokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player])
+      .rpc();
+
+    const playerPass = await program.account.playerPass.fetch(playerPassPda);
+    assert.equal(playerPass.passTokenMint.toString(), passMint.toString());
+
+    let tokenAccount = await getAccount(provider.connection, passTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(Number(tokenAccount.amount), 1);
+
+    const renewalAccounts = {
+      gameState: gameStatePda,
+      burnVault: burnVaultPda,
+      passTier: rankedTierPda,
+      feeConfig: feeConfigPda,
+      priceFeed: null,
+      playerPass: playerPassPda,
+      playerTokenAccount: playerTokenAccount,
+      burnVaultTokenAccount: burnVaultTokenAccount,
+      recoveryVault: recoveryVaultPda,
+      recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+      prizeVault: prizeVaultPda,
+      prizeVaultTokenAccount: prizeVaultTokenAccount,
+      player: player.publicKey,
+      feePayer: player.publicKey,
+      wznMint: wznMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      passMint: null,
+      passTokenAccount: null,
+      passAuthority: null,
+      passTokenProgram: null,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    };
+
+    // Once mirrored, renewals must bring the pass token along
+    try {
+      await program.methods
+        .burnToPlay(RANKED_TIER, 1)
+        .accounts(renewalAccounts)
+        .signers([player])
+        .rpc();
+      assert.fail("Expected the pass token accounts to be required");
+    } catch (err) {
+      assert.include(err.toString(), "PassTokenAccountsRequired");
+    }
+
+    await program.methods
+      .burnToPlay(RANKED_TIER, 1)
+      .accounts({
+        ...renewalAccounts,
+        passMint: passMint,
+        passTokenAccount: passTokenAccount,
+        passAuthority: passAuthority,
+        passTokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([player])
+      .rpc();
+
+    tokenAccount = await getAccount(provider.connection, passTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(Number(tokenAccount.amount), 1);
+  });
+
+  it("Approves sponsor fee payers only through governance", async () => {
+    const sponsor = Keypair.generate();
+    await program.methods
+      .createProposal({ addFeeSponsor: { sponsor: sponsor.publicKey } }, new anchor.BN(0), "Approve event sponsor")
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        proposer: daoMember1.publicKey,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    for (const member of [daoMember1, daoMember2]) {
+      await program.methods
+        .voteOnProposal(7, true)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          voter: member.publicKey,
+        })
+        .signers([member])
+        .rpc();
+    }
+
+    const executeAccounts = (feeConfig: PublicKey | null) => ({
+      daoGovernance: daoGovernancePda,
+      burnVault: burnVaultPda,
+      prizeVault: prizeVaultPda,
+      burnVaultTokenAccount: burnVaultTokenAccount,
+      prizeVaultTokenAccount: prizeVaultTokenAccount,
+      gameState: gameStatePda,
+      feeConfig,
+      recoveryVault: null,
+      recoveryVaultTokenAccount: null,
+      recipientTokenAccount: null,
+      playerScore: null,
+      playerPass: null,
+      executor: daoMember1.publicKey,
+      wznMint: wznMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    // Other proposal types run without the fee config, but a sponsor change needs it
+    try {
+      await program.methods.executeProposal(7).accounts(executeAccounts(null)).signers([daoMember1]).rpc();
+      assert.fail("Expected the fee config to be required");
+    } catch (err) {
+      assert.include(err.toString(), "FeeConfigNotInitialized");
+    }
+
+    await program.methods.executeProposal(7).accounts(executeAccounts(feeConfigPda)).signers([daoMember1]).rpc();
+
+    const feeConfig = await program.account.feeConfig.fetch(feeConfigPda);
+    assert.equal(feeConfig.approvedSponsors.length, 2);
+    assert.equal(feeConfig.approvedSponsors[1].toString(), sponsor.publicKey.toString());
+  });
+
+  it("Pays out of the recovery vault only through governance", async () => {
+    const withdrawAmount = 1000000;
+
+    await program.methods
+      .createProposal(
+        { withdrawRecoveryVault: { recipient: authority.publicKey } },
+        new anchor.BN(withdrawAmount),
+        "Return recovered funds"
+      )
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        proposer: daoMember1.publicKey,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    for (const member of [daoMember1, daoMember2]) {
+      await program.methods
+        .voteOnProposal(8, true)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          voter: member.publicKey,
+        })
+        .signers([member])
+        .rpc();
+    }
+
+    const recipientBefore = await getAccount(provider.connection, authorityTokenAccount);
+    const vaultBefore = await getAccount(provider.connection, recoveryVaultTokenAccount);
+
+    await program.methods
+      .executeProposal(8)
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        burnVault: burnVaultPda,
+        prizeVault: prizeVaultPda,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        gameState: gameStatePda,
+        feeConfig: null,
+        recoveryVault: recoveryVaultPda,
+        recoveryVaultTokenAccount: recoveryVaultTokenAccount,
+        recipientTokenAccount: authorityTokenAccount,
+        playerScore: null,
+        playerPass: null,
+        executor: daoMember1.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    const recipientAfter = await getAccount(provider.connection, authorityTokenAccount);
+    const vaultAfter = await getAccount(provider.connection, recoveryVaultTokenAccount);
+    assert.equal(Number(recipientAfter.amount - recipientBefore.amount), withdrawAmount);
+    assert.equal(Number(vaultBefore.amount - vaultAfter.amount), withdrawAmount);
+
+    const recoveryVault = await program.account.recoveryVault.fetch(recoveryVaultPda);
+    assert.equal(recoveryVault.totalWithdrawn.toNumber(), withdrawAmount);
+  });
+
+  it("Suspends and reinstates a pass through governance", async () => {
+    const [giftPassPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player_pass"), giftRecipient.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const governPass = async (id: number, proposalType: any, description: string, playerPass: PublicKey | null) => {
+      await program.methods
+        .createProposal(proposalType, new anchor.BN(0), description)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          proposer: daoMember1.publicKey,
+        })
+        .signers([daoMember1])
+        .rpc();
+
+      for (const member of [daoMember1, daoMember2]) {
+        await program.methods
+          .voteOnProposal(id, true)
+          .accounts({
+            daoGovernance: daoGovernancePda,
+            voter: member.publicKey,
+          })
+          .signers([member])
+          .rpc();
+      }
+
+      return program.methods
+        .executeProposal(id)
+        .accounts({
+          daoGovernance: daoGovernancePda,
+          burnVault: burnVaultPda,
+          prizeVault: prizeVaultPda,
+          burnVaultTokenAccount: burnVaultTokenAccount,
+          prizeVaultTokenAccount: prizeVaultTokenAccount,
+          gameState: gameStatePda,
+          feeConfig: null,
+          recoveryVault: null,
+          recoveryVaultTokenAccount: null,
+          recipientTokenAccount: null,
+          playerScore: null,
+          playerPass,
+          executor: daoMember1.publicKey,
+          wznMint: wznMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoMember1])
+        .rpc();
+    };
+
+    const before = await program.account.playerPass.fetch(giftPassPda);
+    const suspensionEnd = Math.floor(Date.now() / 1000) + 24 * 60 * 60;
+
+    await governPass(
+      9,
+      { suspendPlayer: { player: giftRecipient.publicKey, reasonCode: 2, suspensionEnd: new anchor.BN(suspensionEnd) } },
+      "Suspend for repeated abandons",
+      giftPassPda
+    );
+
+    const suspended = await program.account.playerPass.fetch(giftPassPda);
+    assert.equal(suspended.isSuspended, true);
+    assert.equal(suspended.suspensionReason, 2);
+    assert.equal(suspended.suspensionEnd.toNumber(), suspensionEnd);
+
+    // The pass has to be supplied for the proposal to run
+    try {
+      await governPass(10, { reinstatePlayer: { player: giftRecipient.publicKey } }, "Lift the suspension", null);
+      assert.fail("Expected the pass to be required");
+    } catch (err) {
+      assert.include(err.toString(), "PlayerPassRequired");
+    }
+
+    await program.methods
+      .executeProposal(10)
+      .accounts({
+        daoGovernance: daoGovernancePda,
+        burnVault: burnVaultPda,
+        prizeVault: prizeVaultPda,
+        burnVaultTokenAccount: burnVaultTokenAccount,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        gameState: gameStatePda,
+        feeConfig: null,
+        recoveryVault: null,
+        recoveryVaultTokenAccount: null,
+        recipientTokenAccount: null,
+        playerScore: null,
+        playerPass: giftPassPda,
+        executor: daoMember1.publicKey,
+        wznMint: wznMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([daoMember1])
+      .rpc();
+
+    const reinstated = await program.account.playerPass.fetch(giftPassPda);
+    assert.equal(reinstated.isSuspended, false);
+    assert(reinstated.passEndTime.toNumber() >= before.passEndTime.toNumber());
+    assert(reinstated.passEndTime.toNumber() < suspended.passEndTime.toNumber());
+  });
+
+  it("Allows monthly reset", async () => {
+    await program.methods
+      .monthlyReset()
+      .accounts({
+        gameState: gameStatePda,
+        leaderboard: leaderboardPda(1),
+        seasonRecord: seasonPda(1),
+        nextLeaderboard: leaderboardPda(2),
+        prizeVault: prizeVaultPda,
+        prizeVaultTokenAccount: prizeVaultTokenAccount,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const gameState = await program.account.gameState.fetch(gameStatePda);
+    assert(gameState.lastMonthlyReset > 0);
+    assert.equal(gameState.currentSeason, 2);
+    assert.equal(gameState.seasonStartBurned.toString(), gameState.totalBurned.toString());
+
+    const season = await program.account.season.fetch(seasonPda(1));
+    const closedLeaderboard = await program.account.leaderboard.fetch(leaderboardPda(1));
+    const vaultTokenAccount = await getAccount(provider.connection, prizeVaultTokenAccount);
+    assert.equal(season.season, 1);
+    assert(season.endedAt.gt(season.startedAt));
+    assert.equal(season.standings.length, closedLeaderboard.entries.length);
+    assert(season.standings.length > 0);
+    assert.equal(season.totalBurned.toString(), gameState.totalBurned.toString());
+    assert.equal(season.prizePool.toString(), vaultTokenAccount.amount.toString());
+
+    const nextLeaderboard = await program.account.leaderboard.fetch(leaderboardPda(2));
+    assert.equal(nextLeaderboard.season, 2);
+    assert.equal(nextLeaderboard.entries.length, 0);
   });
 }); 
\ No newline at end of file